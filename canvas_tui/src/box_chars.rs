@@ -4,11 +4,14 @@
 
 use std::ops::{Index, IndexMut};
 
+use itertools::iproduct;
+
 /// A list of box chars
 ///
 /// They are organized in a binary number in order of up, down, left, right. For example:
 /// `0b1100` represents a vertical line (│) because both up and down are present, but not
 /// left and right.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Chars {
     chars: [char; 16],
 }
@@ -18,10 +21,278 @@ impl Chars {
         Self { chars }
     }
 
+    /// Starts building a custom set of characters, see [`CharsetBuilder`]
+    #[must_use]
+    pub const fn builder() -> CharsetBuilder {
+        CharsetBuilder { chars: EMPTY }
+    }
+
     #[must_use]
     pub fn vertical(&self) -> char { self[0b1100] }
     #[must_use]
     pub fn horizontal(&self) -> char { self[0b0011] }
+
+    /// Blends two sets of characters, taking the vertical lines from `a` and the horizontal
+    /// lines from `b`
+    ///
+    /// Junctions between the two use the mixed-weight characters from unicode (such as `┿`), so
+    /// a rect drawn with a different weight for each direction still connects properly. Characters
+    /// that don't have a mixed-weight version (from a custom set) are taken from `a` if they have
+    /// any vertical lines and `b` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// use box_chars::{Chars, LIGHT, HEAVY};
+    ///
+    /// let chars = Chars::blend(&LIGHT, &HEAVY);
+    /// assert_eq!(chars.vertical(), '│');
+    /// assert_eq!(chars.horizontal(), '━');
+    /// assert_eq!(chars[0b0101], '┍');
+    /// assert_eq!(chars[0b1111], '┿');
+    /// ```
+    #[must_use]
+    pub fn blend(a: &Self, b: &Self) -> Self {
+        let mut chars = EMPTY;
+        for (index, chr) in chars.iter_mut().enumerate() {
+            let vertical = weights(a[index & 0b1100]);
+            let horizontal = weights(b[index & 0b0011]);
+            *chr = match (vertical, horizontal) {
+                (Some((vlight, vheavy)), Some((hlight, hheavy))) => MIXED[vlight | hlight][vheavy | hheavy],
+                _ if index & 0b1100 != 0 => a[index],
+                _ => b[index],
+            };
+        }
+        Self::new(chars)
+    }
+
+    /// Rotates the set of characters 90° clockwise, so the vertical lines take the style of the
+    /// horizontal lines and vice versa
+    ///
+    /// Only characters from [`LIGHT`], [`HEAVY`], or a mixture of them can be rotated, any others
+    /// are left in place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// use box_chars::{Chars, LIGHT, HEAVY};
+    ///
+    /// // light vertical lines and heavy horizontal lines
+    /// let chars = Chars::blend(&LIGHT, &HEAVY);
+    /// assert_eq!(chars[0b1111], '┿');
+    ///
+    /// let rotated = chars.rotate_90();
+    /// assert_eq!(rotated.vertical(), '┃');
+    /// assert_eq!(rotated.horizontal(), '─');
+    /// assert_eq!(rotated[0b1111], '╂');
+    /// ```
+    #[must_use]
+    pub fn rotate_90(&self) -> Self {
+        self.remap(rotate_directions)
+    }
+
+    /// Flips the set of characters horizontally, swapping the style of the left and right lines
+    ///
+    /// Only characters from [`LIGHT`], [`HEAVY`], or a mixture of them can be flipped, any others
+    /// are left in place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// use box_chars::{Chars, LIGHT, HEAVY};
+    ///
+    /// // symmetric sets stay the same
+    /// assert_eq!(LIGHT.flip_horizontal(), LIGHT);
+    /// assert_eq!(Chars::blend(&HEAVY, &LIGHT).flip_horizontal(), Chars::blend(&HEAVY, &LIGHT));
+    /// ```
+    #[must_use]
+    pub fn flip_horizontal(&self) -> Self {
+        self.remap(flip_directions)
+    }
+
+    /// Replaces the character used for one `corner`, leaving the rest unchanged
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// use box_chars::{Chars, CornerIndex, LIGHT};
+    ///
+    /// const ROUNDED_TOP_RIGHT: Chars = LIGHT.with_corner(CornerIndex::TopRight, '╮');
+    ///
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(4, 3));
+    /// canvas.rect(&Just::Centered, &(4, 3), &ROUNDED_TOP_RIGHT)?;
+    ///
+    /// // ┌──╮
+    /// // │  │
+    /// // └──┘
+    /// assert_eq!(canvas.get(&(0, 0))?.text, '┌');
+    /// assert_eq!(canvas.get(&(3, 0))?.text, '╮');
+    /// # Ok(()) }
+    /// ```
+    #[must_use]
+    pub const fn with_corner(self, corner: CornerIndex, chr: char) -> Self {
+        let mut chars = self.chars;
+        chars[corner.index()] = chr;
+        Self::new(chars)
+    }
+
+    /// Moves the style of each character's lines to the directions given by `map`
+    fn remap(&self, map: fn(usize) -> usize) -> Self {
+        let mut chars = self.chars;
+        for (index, &chr) in self.chars.iter().enumerate() {
+            if let Some((light, heavy)) = weights(chr) {
+                chars[map(index)] = MIXED[map(light)][map(heavy)];
+            }
+        }
+        Self::new(chars)
+    }
+}
+
+/// Builds a custom set of [`Chars`] by naming each character instead of using its index
+///
+/// Any character that isn't set is left as a space
+///
+/// # Example
+///
+/// ```
+/// # use canvas_tui::prelude::*;
+/// use box_chars::Chars;
+///
+/// const ASCII: Chars = Chars::builder()
+///     .horizontal('-')
+///     .vertical('|')
+///     .top_left('+').top_right('+').bottom_left('+').bottom_right('+')
+///     .build();
+///
+/// # fn main() -> Result<(), Error> {
+/// let mut canvas = Basic::new(&(4, 3));
+/// canvas.rect(&Just::Centered, &(4, 3), &ASCII)?;
+///
+/// // +--+
+/// // |  |
+/// // +--+
+/// assert_eq!(canvas.get(&(0, 0))?.text, '+');
+/// assert_eq!(canvas.get(&(1, 0))?.text, '-');
+/// assert_eq!(canvas.get(&(0, 1))?.text, '|');
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharsetBuilder {
+    chars: [char; 16],
+}
+
+/// Creates a builder method that sets the character at `$index`
+macro_rules! builder_method {
+    ($(#[$attr:meta])* $name:ident: $index:literal) => {
+        $(#[$attr])*
+        #[must_use]
+        pub const fn $name(mut self, chr: char) -> Self {
+            self.chars[$index] = chr;
+            self
+        }
+    };
+}
+
+impl CharsetBuilder {
+    builder_method! {
+        /// The line going left and right (`─`)
+        horizontal: 0b0011
+    }
+    builder_method! {
+        /// The line going up and down (`│`)
+        vertical: 0b1100
+    }
+    builder_method! {
+        /// The top left corner (`┌`)
+        top_left: 0b0101
+    }
+    builder_method! {
+        /// The top right corner (`┐`)
+        top_right: 0b0110
+    }
+    builder_method! {
+        /// The bottom left corner (`└`)
+        bottom_left: 0b1001
+    }
+    builder_method! {
+        /// The bottom right corner (`┘`)
+        bottom_right: 0b1010
+    }
+    builder_method! {
+        /// The junction on the top edge (`┬`)
+        top_junction: 0b0111
+    }
+    builder_method! {
+        /// The junction on the bottom edge (`┴`)
+        bottom_junction: 0b1011
+    }
+    builder_method! {
+        /// The junction on the left edge (`├`)
+        left_junction: 0b1101
+    }
+    builder_method! {
+        /// The junction on the right edge (`┤`)
+        right_junction: 0b1110
+    }
+    builder_method! {
+        /// The junction in the middle (`┼`)
+        cross: 0b1111
+    }
+
+    /// Finishes building the set of characters
+    #[must_use]
+    pub const fn build(self) -> Chars {
+        Chars::new(self.chars)
+    }
+}
+
+/// One of the four corners of a box, see [`Chars::with_corner`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CornerIndex {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl CornerIndex {
+    /// The index of the corner in [`Chars`]
+    #[must_use]
+    pub const fn index(self) -> usize {
+        match self {
+            Self::TopLeft => 0b0101,
+            Self::TopRight => 0b0110,
+            Self::BottomLeft => 0b1001,
+            Self::BottomRight => 0b1010,
+        }
+    }
+}
+
+/// Rotates the directions in `dirs` 90° clockwise
+const fn rotate_directions(dirs: usize) -> usize {
+    (dirs & 0b1000) >> 3 // up -> right
+        | (dirs & 0b0001) << 2 // right -> down
+        | (dirs & 0b0100) >> 1 // down -> left
+        | (dirs & 0b0010) << 2 // left -> up
+}
+
+/// Flips the directions in `dirs` horizontally
+const fn flip_directions(dirs: usize) -> usize {
+    dirs & 0b1100 // up and down stay
+        | (dirs & 0b0010) >> 1 // left -> right
+        | (dirs & 0b0001) << 1 // right -> left
+}
+
+/// Gets the light and heavy directions of `chr` from [`MIXED`], if it is in there
+fn weights(chr: char) -> Option<(usize, usize)> {
+    iproduct!(0..16, 0..16)
+        .filter(|(light, heavy)| light & heavy == 0)
+        .find(|&(light, heavy)| MIXED[light][heavy] == chr)
 }
 
 impl Index<usize> for Chars {
@@ -98,3 +369,93 @@ pub const HEAVY: Chars = {
     chars[0b1111] = '╋';
     Chars::new(chars)
 };
+
+/// Every combination of light and heavy box characters, indexed by `[light][heavy]`
+///
+/// Each index uses the same format as [`Chars`], and only combinations where the light and heavy
+/// directions don't overlap are filled in
+const MIXED: [[char; 16]; 16] = {
+    let mut table = [EMPTY; 16];
+    table[0b0000][0b0000] = ' ';
+    table[0b0000][0b0001] = '╺';
+    table[0b0000][0b0010] = '╸';
+    table[0b0000][0b0011] = '━';
+    table[0b0000][0b0100] = '╻';
+    table[0b0000][0b0101] = '┏';
+    table[0b0000][0b0110] = '┓';
+    table[0b0000][0b0111] = '┳';
+    table[0b0000][0b1000] = '╹';
+    table[0b0000][0b1001] = '┗';
+    table[0b0000][0b1010] = '┛';
+    table[0b0000][0b1011] = '┻';
+    table[0b0000][0b1100] = '┃';
+    table[0b0000][0b1101] = '┣';
+    table[0b0000][0b1110] = '┫';
+    table[0b0000][0b1111] = '╋';
+    table[0b0001][0b0000] = '╶';
+    table[0b0001][0b0010] = '╾';
+    table[0b0001][0b0100] = '┎';
+    table[0b0001][0b0110] = '┱';
+    table[0b0001][0b1000] = '┖';
+    table[0b0001][0b1010] = '┹';
+    table[0b0001][0b1100] = '┠';
+    table[0b0001][0b1110] = '╉';
+    table[0b0010][0b0000] = '╴';
+    table[0b0010][0b0001] = '╼';
+    table[0b0010][0b0100] = '┒';
+    table[0b0010][0b0101] = '┲';
+    table[0b0010][0b1000] = '┚';
+    table[0b0010][0b1001] = '┺';
+    table[0b0010][0b1100] = '┨';
+    table[0b0010][0b1101] = '╊';
+    table[0b0011][0b0000] = '─';
+    table[0b0011][0b0100] = '┰';
+    table[0b0011][0b1000] = '┸';
+    table[0b0011][0b1100] = '╂';
+    table[0b0100][0b0000] = '╷';
+    table[0b0100][0b0001] = '┍';
+    table[0b0100][0b0010] = '┑';
+    table[0b0100][0b0011] = '┯';
+    table[0b0100][0b1000] = '╿';
+    table[0b0100][0b1001] = '┡';
+    table[0b0100][0b1010] = '┩';
+    table[0b0100][0b1011] = '╇';
+    table[0b0101][0b0000] = '┌';
+    table[0b0101][0b0010] = '┭';
+    table[0b0101][0b1000] = '┞';
+    table[0b0101][0b1010] = '╃';
+    table[0b0110][0b0000] = '┐';
+    table[0b0110][0b0001] = '┮';
+    table[0b0110][0b1000] = '┦';
+    table[0b0110][0b1001] = '╄';
+    table[0b0111][0b0000] = '┬';
+    table[0b0111][0b1000] = '╀';
+    table[0b1000][0b0000] = '╵';
+    table[0b1000][0b0001] = '┕';
+    table[0b1000][0b0010] = '┙';
+    table[0b1000][0b0011] = '┷';
+    table[0b1000][0b0100] = '╽';
+    table[0b1000][0b0101] = '┢';
+    table[0b1000][0b0110] = '┪';
+    table[0b1000][0b0111] = '╈';
+    table[0b1001][0b0000] = '└';
+    table[0b1001][0b0010] = '┵';
+    table[0b1001][0b0100] = '┟';
+    table[0b1001][0b0110] = '╅';
+    table[0b1010][0b0000] = '┘';
+    table[0b1010][0b0001] = '┶';
+    table[0b1010][0b0100] = '┧';
+    table[0b1010][0b0101] = '╆';
+    table[0b1011][0b0000] = '┴';
+    table[0b1011][0b0100] = '╁';
+    table[0b1100][0b0000] = '│';
+    table[0b1100][0b0001] = '┝';
+    table[0b1100][0b0010] = '┥';
+    table[0b1100][0b0011] = '┿';
+    table[0b1101][0b0000] = '├';
+    table[0b1101][0b0010] = '┽';
+    table[0b1110][0b0000] = '┤';
+    table[0b1110][0b0001] = '┾';
+    table[0b1111][0b0000] = '┼';
+    table
+};
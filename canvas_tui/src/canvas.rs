@@ -4,9 +4,10 @@
 
 use crate::{prelude::*, widgets::WidgetSource};
 
-use super::{num::{Pos, Size}, shapes::{Rect, Single, Grid}};
+use super::{num::{Pos, Size}, shapes::{DrawnShape, Rect, Single, Grid, VariableGrid}};
 use array2d::Array2D;
 use itertools::iproduct;
+use unicode_width::UnicodeWidthChar;
 use crate::Error;
 
 #[allow(clippy::missing_const_for_fn)]
@@ -31,10 +32,104 @@ pub fn check_bounds(pos: Vec2, size: Vec2, canvas: &impl Size, name: &'static st
     Ok(())
 }
 
+/// Checks that a window at `pos` with size `size` fits inside of `canvas`
+fn check_window(pos: Vec2, size: Vec2, canvas: &impl Size) -> Result<(), Error> {
+    if pos.x < 0 || pos.y < 0 {
+        return Err(Error::OutOfBounds(pos.x, pos.y));
+    }
+    check_bounds(pos, size, canvas, "window")
+}
+
+/// Written into the second cell of a double-width character by [`Canvas::text_absolute`]
+///
+/// The character before it already covers the cell when printed, and terminals ignore `'\0'`
+pub const WIDE_CHAR_PLACEHOLDER: char = '\0';
+
+/// The amount of cells `chr` takes up when displayed, either 1 or 2
+///
+/// # Example
+///
+/// ```
+/// # use canvas_tui::canvas::char_width;
+/// assert_eq!(char_width('a'), 1);
+/// assert_eq!(char_width('日'), 2);
+/// ```
+#[must_use]
+pub fn char_width(chr: char) -> isize {
+    match UnicodeWidthChar::width(chr) {
+        Some(2) => 2,
+        _ => 1,
+    }
+}
+
+/// The amount of cells `string` takes up when displayed, see [`char_width`]
+#[allow(clippy::missing_errors_doc)]
+pub fn display_width(string: &str) -> Result<isize, Error> {
+    let width: usize = string.chars().map(|chr| char_width(chr).unsigned_abs()).sum();
+    width.try_into().map_err(|_| Error::TooLarge("string length", width))
+}
+
+/// The start of `string` that fits within `max_width` cells when displayed
+pub(crate) fn take_width(string: &str, max_width: isize) -> String {
+    let mut width = 0;
+    string.chars()
+        .take_while(|&chr| {
+            width += char_width(chr);
+            width <= max_width
+        })
+        .collect()
+}
+
+/// Formats `color` as a hex code usable in html and svg
+fn css_color(Color { r, g, b }: Color) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+/// Pushes `chr` onto `out`, escaping it if it's special in html and svg
+fn push_escaped(out: &mut String, chr: char) {
+    match chr {
+        '&' => out.push_str("&amp;"),
+        '<' => out.push_str("&lt;"),
+        '>' => out.push_str("&gt;"),
+        chr => out.push(chr),
+    }
+}
+
+/// Pushes a run of escaped text onto `html`, wrapping it in a span if it has any colors
+fn html_run(html: &mut String, run: &str, (foreground, background): (Option<Color>, Option<Color>)) {
+    if run.is_empty() { return; }
+    if foreground.is_none() && background.is_none() {
+        html.push_str(run);
+        return;
+    }
+
+    html.push_str("<span style=\"");
+    if let Some(foreground) = foreground {
+        html.push_str(&format!("color:{};", css_color(foreground)));
+    }
+    if let Some(background) = background {
+        html.push_str(&format!("background-color:{};", css_color(background)));
+    }
+    html.push_str("\">");
+    html.push_str(run);
+    html.push_str("</span>");
+}
+
 fn full_grid_size(cell_size: Vec2, dims: Vec2) -> Vec2 {
     (cell_size + 1) * dims + 1
 }
 
+/// The offsets of the lines in between cells of `sizes`, for [`Canvas::variable_grid_absolute`]
+fn inner_lines(sizes: &[isize]) -> Vec<isize> {
+    let Some((_, sizes)) = sizes.split_last() else { return Vec::new() };
+    sizes.iter()
+        .scan(0, |line, size| {
+            *line += size + 1;
+            Some(*line)
+        })
+        .collect()
+}
+
 /// A cell of a canvas, holding the text and highlight
 pub struct Cell {
     pub text: char,
@@ -42,6 +137,31 @@ pub struct Cell {
     pub background: Option<Color>,
 }
 
+/// What happens when text runs past the right edge of the canvas
+///
+/// See [`Canvas::text_absolute_with_mode`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextOverflowMode {
+    /// Returns [`Error::TextOverflow`], the same as [`Canvas::text_absolute`]
+    #[default]
+    Error,
+    /// Stops writing at the edge of the canvas
+    Truncate,
+    /// Continues on the next row, starting at the same column as the text
+    Wrap,
+}
+
+/// Which way a gradient goes
+///
+/// See [`Canvas::fill_with_gradient`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradientDirection {
+    /// From the left column to the right column
+    Horizontal,
+    /// From the top row to the bottom row
+    Vertical,
+}
+
 /// A canvas of text and color
 ///
 /// See [`Basic`] for a generic canvas
@@ -86,8 +206,9 @@ pub trait Canvas : Size + Sized {
     ///
     /// - If the index is out of bounds
     fn set_without_catch(&mut self, pos: Vec2, chr: char) -> Result<&mut Self::Output, Error>;
-    /// Highlights `pos` with `foreground` and `background`, if they are given,
-    /// without [catching](Self::catch) any errors.
+    /// Highlights `pos` with `foreground` and `background` without [catching](Self::catch) any errors.
+    ///
+    /// Each color is either set, cleared with [`None`], or left as is with [`ColorUpdate::Keep`]
     ///
     /// **Note:** This is mainly meant to be used internally, see [highlight](Canvas::highlight) instead
     ///
@@ -97,8 +218,8 @@ pub trait Canvas : Size + Sized {
     fn highlight_without_catch(
         &mut self,
         pos: Vec2,
-        foreground: Option<Color>,
-        background: Option<Color>
+        foreground: ColorUpdate,
+        background: ColorUpdate
     ) -> Result<&mut Self::Output, Error>;
     /// Writes `chr` onto the canvas at `pos`
     ///
@@ -128,7 +249,10 @@ pub trait Canvas : Size + Sized {
         // this unwrap is fine because the error is already checked
         else { Ok(DrawInfo::single(canvas, pos)) }
     }
-    /// Highlights `pos` with `foreground` and `background`, if they are given
+    /// Highlights `pos` with `foreground` and `background`
+    ///
+    /// Each color is either set, cleared by passing [`None`], or left as is by passing
+    /// [`ColorUpdate::Keep`]
     ///
     /// # Errors
     ///
@@ -140,7 +264,14 @@ pub trait Canvas : Size + Sized {
     /// # use canvas_tui::prelude::*;
     /// # fn main() -> Result<(), Error> {
     /// let mut canvas = Basic::new(&(3, 3));
-    /// canvas.highlight(&(1, 1), Color::grayscale(255), None)?;
+    /// canvas.highlight(&(1, 1), Color::grayscale(255), Color::BLACK)?;
+    ///
+    /// let cell = canvas.get(&(1, 1))?;
+    /// assert_eq!(cell.foreground, Some(Color::grayscale(255)));
+    /// assert_eq!(cell.background, Some(Color::BLACK));
+    ///
+    /// // keep the foreground and clear the background
+    /// canvas.highlight(&(1, 1), ColorUpdate::Keep, None)?;
     ///
     /// let cell = canvas.get(&(1, 1))?;
     /// assert_eq!(cell.foreground, Some(Color::grayscale(255)));
@@ -150,8 +281,8 @@ pub trait Canvas : Size + Sized {
     fn highlight(
         &mut self,
         pos: &impl Pos,
-        foreground: impl Into<Option<Color>>,
-        background: impl Into<Option<Color>>
+        foreground: impl Into<ColorUpdate>,
+        background: impl Into<ColorUpdate>
     ) -> DrawResult<Self::Output, Single> {
         let canvas = self.base_canvas()?;
         // see set
@@ -197,6 +328,174 @@ pub trait Canvas : Size + Sized {
         widget.draw(&mut canvas.window_absolute(&pos, &size)?)?;
         Ok(DrawInfo::rect(canvas, pos, size))
     }
+    /// Draws a [widget](Widget) onto the canvas with its top left corner at `pos`
+    ///
+    /// Useful when the position has already been worked out, such as from [`Canvas::measure`]
+    ///
+    /// # Errors
+    ///
+    /// - If the widget doesn't have enough space
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(7, 3));
+    /// let info = canvas.draw_absolute(&(2, 1), widgets::basic::title("foo", None, None))?;
+    /// assert_eq!(info.shape.size, (5, 1));
+    ///
+    /// // .......
+    /// // .. foo
+    /// // .......
+    /// assert_eq!(canvas.get(&(3, 1))?.text, 'f');
+    /// # Ok(()) }
+    /// ```
+    fn draw_absolute<W: WidgetSource>(&mut self, pos: &impl Pos, widget: W) -> DrawResult<Self::Output, Rect> {
+        let widget = widget.build();
+        let canvas = self.base_canvas()?;
+        let size = widget.size(canvas)?;
+        let pos = Vec2::from_pos(pos);
+        canvas.catch(check_bounds(pos, size, canvas, W::Output::name()))?;
+        widget.draw(&mut canvas.window_absolute(&pos, &size)?)?;
+        Ok(DrawInfo::rect(canvas, pos, size))
+    }
+    /// Draws a [widget](Widget) onto the canvas using `justification`, or skips it if it doesn't fit
+    ///
+    /// Returns `None` without drawing anything if the widget doesn't have enough space, otherwise
+    /// the result of drawing it, see [`Canvas::draw`]. Any outstanding error in the canvas is
+    /// still returned
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(7, 3));
+    /// assert!(canvas.try_draw(&Just::Centered, widgets::basic::title("foobar", None, None)).is_none());
+    /// assert!(canvas.try_draw(&Just::Centered, widgets::basic::title("foo", None, None)).is_some());
+    ///
+    /// // .......
+    /// // . foo .
+    /// // .......
+    /// assert_eq!(canvas.get(&(2, 1))?.text, 'f');
+    /// # Ok(()) }
+    /// ```
+    fn try_draw<W: WidgetSource>(&mut self, justification: &Just, widget: W) -> Option<DrawResult<Self::Output, Rect>> {
+        // only skip when this widget doesn't fit, not when an earlier draw failed
+        if let Err(err) = self.error() { return Some(Err(err)); }
+        let widget = widget.build();
+        match self.measure(justification, &widget) {
+            Ok((pos, _)) => Some(self.draw_absolute(&pos, widget)),
+            Err(Error::JustificationOutOfBounds { .. } | Error::ItemTooBig { .. }) => None,
+            Err(err) => Some(Err(err)),
+        }
+    }
+    /// Computes where a [widget](Widget) would be drawn using `justification` without drawing it
+    ///
+    /// Returns the position and size of the widget, see [`Canvas::draw`]
+    ///
+    /// # Errors
+    ///
+    /// - If there is an outstanding error in the canvas
+    /// - If the widget doesn't have enough space
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let canvas = Basic::new(&(7, 3));
+    /// let (pos, size) = canvas.measure(&Just::Centered, &widgets::basic::title("foo", None, None))?;
+    ///
+    /// // .......
+    /// // . foo .
+    /// // .......
+    /// assert_eq!(pos, (1, 1));
+    /// assert_eq!(size, (5, 1));
+    /// # Ok(()) }
+    /// ```
+    fn measure<W: Widget>(&self, justification: &Just, widget: &W) -> Result<(Vec2, Vec2), Error> {
+        self.error()?;
+        let size = widget.size(self)?;
+        let pos = justification.get(self, &size)?;
+        check_bounds(pos, size, self, W::name())?;
+        Ok((pos, size))
+    }
+    /// Draws each [widget](Widget) in `widgets` in order using its justification
+    ///
+    /// Returns the rect each widget was drawn to, see [`Canvas::draw`]
+    ///
+    /// # Errors
+    ///
+    /// - If any widget doesn't have enough space, stopping at the first one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// use widgets::basic::title;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(7, 3));
+    /// let rects = canvas.draw_many([
+    ///     (Just::CenterLeft, title("a", None, None)),
+    ///     (Just::CenterRight, title("b", None, None)),
+    /// ])?;
+    ///
+    /// // .......
+    /// // . a b .
+    /// // .......
+    /// assert_eq!(rects[0].pos, (1, 1));
+    /// assert_eq!(rects[1].pos, (3, 1));
+    /// assert_eq!(canvas.get(&(4, 1))?.text, 'b');
+    /// # Ok(()) }
+    /// ```
+    fn draw_many<W: WidgetSource, I: IntoIterator<Item = (Just, W)>>(&mut self, widgets: I) -> Result<Vec<Rect>, Error> {
+        widgets.into_iter()
+            .map(|(justification, widget)| self.draw(&justification, widget).map(|info| info.shape))
+            .collect()
+    }
+    /// Draws a [widget](Widget) inside of a box onto the canvas using `justification`
+    ///
+    /// The box is sized to fit the widget, and the inside is filled with spaces before the widget
+    /// is drawn. The returned profile is the box, including the border.
+    ///
+    /// # Errors
+    ///
+    /// - If the widget and the box don't have enough space
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(9, 5));
+    /// canvas.draw_box(&Just::Centered, &box_chars::LIGHT, widgets::basic::title("foo", None, None))?;
+    ///
+    /// // .........
+    /// // .┌─────┐.
+    /// // .│ foo │.
+    /// // .└─────┘.
+    /// // .........
+    /// assert_eq!(canvas.get(&(1, 1))?.text, '┌');
+    /// assert_eq!(canvas.get(&(3, 2))?.text, 'f');
+    /// assert_eq!(canvas.get(&(7, 3))?.text, '┘');
+    /// # Ok(()) }
+    /// ```
+    fn draw_box<W: WidgetSource>(
+        &mut self,
+        justification: &Just,
+        chars: &'static box_chars::Chars,
+        widget: W
+    ) -> DrawResult<Self::Output, Rect> {
+        let widget = widget.build();
+        let canvas = self.base_canvas()?;
+        // the widget only has the inside of the box to work with
+        let size = widget.size(&(Vec2::from_size(canvas) - 2))?;
+        let Rect { pos, size: outer } = canvas.rect_filled(justification, &(size + 2), chars, ' ')?.shape;
+        widget.draw(&mut canvas.window_absolute(&(pos + 1), &size)?)?;
+        Ok(DrawInfo::rect(canvas, pos, outer))
+    }
     /// Creates a window of size `size` onto the canvas at `pos`
     ///
     /// # Errors
@@ -305,8 +604,8 @@ pub trait Canvas : Size + Sized {
         &mut self,
         pos: &impl Pos,
         size: &impl Size,
-        foreground: impl Into<Option<Color>>,
-        background: impl Into<Option<Color>>
+        foreground: impl Into<ColorUpdate>,
+        background: impl Into<ColorUpdate>
     ) -> DrawResult<Self::Output, Rect> {
         let canvas = self.base_canvas()?;
 
@@ -324,6 +623,63 @@ pub trait Canvas : Size + Sized {
 
         Ok(DrawInfo::rect(canvas, pos, size))
     }
+    /// Highlights a drop shadow for `shape`, the part of `shape` moved by `offset` that isn't
+    /// covered by `shape` itself
+    ///
+    /// The shadow is clipped to the bounds of the canvas, and the returned shape is the moved
+    /// `shape` after clipping
+    ///
+    /// # Errors
+    ///
+    /// - If the canvas has errored
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(5, 4));
+    /// let rect = canvas.rect(&Just::OffTopLeftBy(0), &(3, 3), &box_chars::LIGHT)?.shape;
+    /// canvas.shadow(&rect, Vec2::new(1, 1), None, Color::new(0, 0, 0))?;
+    ///
+    /// // ┌─┐..
+    /// // │.│-.
+    /// // └─┘-. (shadow represented by -)
+    /// // .---.
+    /// assert_eq!(canvas.get(&(3, 1))?.background, Some(Color::new(0, 0, 0)));
+    /// assert_eq!(canvas.get(&(1, 3))?.background, Some(Color::new(0, 0, 0)));
+    /// assert_eq!(canvas.get(&(2, 2))?.background, None);
+    /// # Ok(()) }
+    /// ```
+    fn shadow(
+        &mut self,
+        shape: &Rect,
+        offset: Vec2,
+        foreground: impl Into<Option<Color>>,
+        background: impl Into<Option<Color>>
+    ) -> DrawResult<Self::Output, Rect> {
+        let canvas = self.base_canvas()?;
+
+        let foreground = foreground.into();
+        let background = background.into();
+
+        // clip the moved shape to the canvas
+        let shape_end = shape.pos + shape.size;
+        let start = shape.pos + offset;
+        let start = Vec2::new(start.x.max(0), start.y.max(0));
+        let end = shape_end + offset;
+        let end = Vec2::new(end.x.min(canvas.width()), end.y.min(canvas.height()));
+
+        for (x, y) in iproduct!(start.x..end.x, start.y..end.y) {
+            let covered = (shape.pos.x..shape_end.x).contains(&x) && (shape.pos.y..shape_end.y).contains(&y);
+            if !covered {
+                canvas.highlight(&(x, y), foreground, background)?;
+            }
+        }
+
+        let size = Vec2::new((end.x - start.x).max(0), (end.y - start.y).max(0));
+        Ok(DrawInfo::rect(canvas, start, size))
+    }
     /// Sets a box of the canvas with `chr` starting at `pos` and extending bottom right for `size`
     ///
     /// # Errors
@@ -365,11 +721,14 @@ pub trait Canvas : Size + Sized {
 
         Ok(DrawInfo::rect(canvas, pos, size))
     }
-    /// Writes some text on the canvas at `pos`
+    /// Colors the background of a box at `pos` with a gradient from `start` to `end`, see [`Color::blend`]
+    ///
+    /// Each column (or row if `direction` is [`Vertical`](GradientDirection::Vertical)) is one
+    /// step of the gradient, with `start` on the first and `end` on the last
     ///
     /// # Errors
     ///
-    /// - If there isn't enough space
+    /// - If the box is out of bounds
     ///
     /// # Example
     ///
@@ -377,22 +736,50 @@ pub trait Canvas : Size + Sized {
     /// # use canvas_tui::prelude::*;
     /// # fn main() -> Result<(), Error> {
     /// let mut canvas = Basic::new(&(5, 3));
-    /// canvas.text(&Just::Centered, "hello")?;
+    /// canvas.text(&Just::Centered, "foo").foreground(Color::new(255, 0, 0))?;
+    /// canvas.fill_with_gradient(&(0, 1), &(5, 1), Color::BLACK, Color::WHITE, GradientDirection::Horizontal)?;
     ///
     /// // .....
-    /// // hello
+    /// // -foo- (gradient from black to white represented by -)
     /// // .....
-    /// assert_eq!(canvas.get(&(1, 1))?.text, 'e');
+    /// assert_eq!(canvas.get(&(0, 1))?.background, Some(Color::BLACK));
+    /// assert_eq!(canvas.get(&(2, 1))?.background, Some(Color::grayscale(128)));
+    /// // the text keeps its color
+    /// assert_eq!(canvas.get(&(2, 1))?.foreground, Some(Color::new(255, 0, 0)));
+    /// assert_eq!(canvas.get(&(4, 1))?.background, Some(Color::WHITE));
     /// # Ok(()) }
     /// ```
-    fn text(&mut self, justification: &Just, string: &str) -> DrawResult<Self::Output, Rect> {
-        self.error()?;
-        let len = string.chars().count()
-            .try_into()
-            .map_err(|_| Error::TooLarge("string length", string.len()));
-        let size = (self.catch(len)?, 1);
-        let pos = self.catch(justification.get(self, &size))?;
-        self.text_absolute(&pos, string)
+    #[allow(clippy::cast_precision_loss)]
+    fn fill_with_gradient(
+        &mut self,
+        pos: &impl Pos,
+        size: &impl Size,
+        start: Color,
+        end: Color,
+        direction: GradientDirection,
+    ) -> DrawResult<Self::Output, Rect> {
+        let canvas = self.base_canvas()?;
+
+        let pos = Vec2::from_pos(pos);
+        let size = Vec2::from_size(size);
+        canvas.catch(check_bounds(pos, size, canvas, "gradient"))?;
+
+        let steps = match direction {
+            GradientDirection::Horizontal => size.width(),
+            GradientDirection::Vertical => size.height(),
+        };
+        for offset in iproduct!(0..size.width(), 0..size.height()) {
+            let offset = Vec2::from(offset);
+            let step = match direction {
+                GradientDirection::Horizontal => offset.x,
+                GradientDirection::Vertical => offset.y,
+            };
+            // a single step is just the start color
+            let amount = if steps > 1 { step as f32 / (steps - 1) as f32 } else { 0.0 };
+            canvas.highlight(&(pos + offset), ColorUpdate::Keep, start.blend(end, amount))?;
+        }
+
+        Ok(DrawInfo::rect(canvas, pos, size))
     }
     /// Writes some text on the canvas at `pos`
     ///
@@ -406,7 +793,7 @@ pub trait Canvas : Size + Sized {
     /// # use canvas_tui::prelude::*;
     /// # fn main() -> Result<(), Error> {
     /// let mut canvas = Basic::new(&(5, 3));
-    /// canvas.text_absolute(&(0, 1), "hello")?;
+    /// canvas.text(&Just::Centered, "hello")?;
     ///
     /// // .....
     /// // hello
@@ -414,111 +801,498 @@ pub trait Canvas : Size + Sized {
     /// assert_eq!(canvas.get(&(1, 1))?.text, 'e');
     /// # Ok(()) }
     /// ```
-    fn text_absolute(&mut self, pos: &impl Pos, string: &str) -> DrawResult<Self::Output, Rect> {
-        let canvas = self.base_canvas()?;
-
-        let canvas_size = Vec2::from_size(canvas);
-        let pos = Vec2::from_pos(pos);
-        for (charnum, chr) in (0..).zip(string.chars()) {
-            let charpos = pos.add_x(charnum);
-            catch!(canvas.set_without_catch(charpos, chr)
-                // add a nice error
-                .map_err(|_| Error::TextOverflow { starting: pos, text: string.to_owned(), ending: charpos, canvas: canvas_size })
-            );
-        }
-
-        let textsize = canvas.catch((string.chars().count(), 1).try_into())?;
-        Ok(DrawInfo::rect(canvas, pos, textsize))
+    fn text(&mut self, justification: &Just, string: &str) -> DrawResult<Self::Output, Rect> {
+        self.error()?;
+        let size = (self.catch(display_width(string))?, 1);
+        let pos = self.catch(justification.get(self, &size))?;
+        self.text_absolute(&pos, string)
     }
-    /// Draws a box onto the canvas using `justification` with size `size`
-    ///
-    /// See [`DrawResultMethods::draw_inside`] to draw on the inside of the rect
+    /// Writes some text on the canvas using `justification`, cutting it off with `ellipsis` if
+    /// it's displayed wider than `max_width`
     ///
     /// # Errors
     ///
-    /// - If there isn't enough space
+    /// - If there isn't enough space for the text after it's cut off
     ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// # use canvas_tui::prelude::*;
     /// # fn main() -> Result<(), Error> {
-    /// let mut canvas = Basic::new(&(5, 5));
-    /// canvas.rect(&Just::Centered, &(3, 3), &box_chars::LIGHT)?;
-    ///
-    /// // .....
-    /// // .┌─┐.
-    /// // .│.│.
-    /// // .└─┘.
-    /// // .....
-    /// assert_eq!(canvas.get(&(1, 1))?.text, '┌');
-    /// assert_eq!(canvas.get(&(2, 1))?.text, '─');
+    /// let mut canvas = Basic::new(&(7, 3));
+    /// canvas.text_truncate(&Just::Centered, "hello world", 5, "...")?;
+    ///
+    /// // .......
+    /// // .he....
+    /// // .......
+    /// assert_eq!(canvas.get(&(2, 1))?.text, 'e');
+    /// assert_eq!(canvas.get(&(3, 1))?.text, '.');
     /// # Ok(()) }
     /// ```
-    fn rect(&mut self, justification: &Just, size: &impl Size, chars: &'static box_chars::Chars) -> DrawResult<Self::Output, Rect> {
+    fn text_truncate(&mut self, justification: &Just, string: &str, max_width: usize, ellipsis: &str) -> DrawResult<Self::Output, Rect> {
         self.error()?;
-        let pos = self.catch(justification.get(self, size))?;
-        self.rect_absolute(&pos, size, chars)
+        let max_width = self.catch(isize::try_from(max_width).map_err(|_| Error::TooLarge("max width", max_width)))?;
+        if self.catch(display_width(string))? <= max_width {
+            return self.text(justification, string);
+        }
+
+        // fit as much of the text as possible before the ellipsis,
+        // cutting the ellipsis itself if it's too wide
+        let room = max_width - self.catch(display_width(ellipsis))?;
+        let mut truncated = take_width(string, room);
+        truncated += &take_width(ellipsis, max_width - self.catch(display_width(&truncated))?);
+        self.text(justification, &truncated)
     }
-    /// Draws a box onto the canvas at `pos` with size `size`
+    /// Writes some text on the canvas using `justification`, starting a new row at each newline
     ///
-    /// See [`DrawResultMethods::draw_inside`] to draw on the inside of the rect
+    /// Each line starts at the same column, and the lines are justified together as a block as
+    /// wide as the longest line
     ///
     /// # Errors
     ///
     /// - If there isn't enough space
     ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// # use canvas_tui::prelude::*;
     /// # fn main() -> Result<(), Error> {
-    /// let mut canvas = Basic::new(&(5, 5));
-    /// canvas.rect_absolute(&(1, 1), &(3, 3), &box_chars::LIGHT)?;
-    ///
-    /// // .....
-    /// // .┌─┐.
-    /// // .│.│.
-    /// // .└─┘.
-    /// // .....
-    /// assert_eq!(canvas.get(&(1, 1))?.text, '┌');
-    /// assert_eq!(canvas.get(&(2, 1))?.text, '─');
+    /// let mut canvas = Basic::new(&(7, 4));
+    /// canvas.text_multiline(&Just::Centered, "hello\nfoo")?;
+    ///
+    /// // .......
+    /// // .hello.
+    /// // .foo...
+    /// // .......
+    /// assert_eq!(canvas.get(&(1, 1))?.text, 'h');
+    /// assert_eq!(canvas.get(&(1, 2))?.text, 'f');
+    /// assert_eq!(canvas.get(&(4, 2))?.text, ' ');
     /// # Ok(()) }
     /// ```
-    fn rect_absolute(&mut self, pos: &impl Pos, size: &impl Size, chars: &'static box_chars::Chars) -> DrawResult<Self::Output, Rect> {
+    fn text_multiline(&mut self, justification: &Just, string: &str) -> DrawResult<Self::Output, Rect> {
         let canvas = self.base_canvas()?;
 
-        let size = Vec2::from_size(size);
-        let pos = Vec2::from_pos(pos);
-        canvas.catch(check_bounds(pos, size, canvas, "rect"))?;
-
-        let top = 0;
-        let bottom = size.height() - 1;
-        let left = 0;
-        let right = size.width() - 1;
-
-        for x in (left + 1)..right {
-            canvas.set(&(pos + (x, top)), chars.horizontal())?;
-            canvas.set(&(pos + (x, bottom)), chars.horizontal())?;
-        }
-
-        for y in (top + 1)..bottom {
-            canvas.set(&(pos + (left, y)), chars.vertical())?;
-            canvas.set(&(pos + (right, y)), chars.vertical())?;
+        let width = string.lines()
+            .map(display_width)
+            .try_fold(0, |max, width| width.map(|width| width.max(max)));
+        let width = canvas.catch(width)?;
+        let lines = string.lines().count();
+        let height = canvas.catch(lines.try_into().map_err(|_| Error::TooLarge("line count", lines)))?;
+        let size = Vec2::new(width, height);
+
+        let pos = canvas.catch(justification.get(canvas, &size))?;
+        for (line, y) in string.lines().zip(0..) {
+            canvas.text_absolute(&pos.add_y(y), line)?;
         }
 
-        // set corners                             udlr
-        canvas.set(&(pos + (left, top)),     chars[0b0101])?;
-        canvas.set(&(pos + (right, top)),    chars[0b0110])?;
-        canvas.set(&(pos + (left, bottom)),  chars[0b1001])?;
-        canvas.set(&(pos + (right, bottom)), chars[0b1010])?;
-
         Ok(DrawInfo::rect(canvas, pos, size))
     }
-    /// Draws a box onto the canvas with justification `just`, grid dimensions `dims`, cell size
-    /// `cell_size`, and using box chars `chars` 
+    /// Writes some text on the canvas so that it ends at the point given by `justification`
     ///
-    /// See [`DrawResultMethods::draw_inside`] to draw on the inside of the grid
+    /// The point is where a single character would be placed using `justification`
+    ///
+    /// # Errors
+    ///
+    /// - If there isn't enough space
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(7, 3));
+    /// canvas.text_right(&Just::Centered, "foo")?;
+    ///
+    /// // .......
+    /// // .foo...
+    /// // .......
+    /// assert_eq!(canvas.get(&(1, 1))?.text, 'f');
+    /// assert_eq!(canvas.get(&(3, 1))?.text, 'o');
+    /// # Ok(()) }
+    /// ```
+    fn text_right(&mut self, justification: &Just, string: &str) -> DrawResult<Self::Output, Rect> {
+        self.error()?;
+        let len = self.catch(display_width(string))?;
+        let pos = self.catch(justification.get(self, &(1, 1)))?;
+        self.text_absolute(&pos.sub_x((len - 1).max(0)), string)
+    }
+    /// Writes some text on the canvas so that it starts at the point given by `justification`
+    ///
+    /// The point is where a single character would be placed using `justification`
+    ///
+    /// # Errors
+    ///
+    /// - If there isn't enough space
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(7, 3));
+    /// canvas.text_left(&Just::Centered, "foo")?;
+    ///
+    /// // .......
+    /// // ...foo.
+    /// // .......
+    /// assert_eq!(canvas.get(&(3, 1))?.text, 'f');
+    /// assert_eq!(canvas.get(&(5, 1))?.text, 'o');
+    /// # Ok(()) }
+    /// ```
+    fn text_left(&mut self, justification: &Just, string: &str) -> DrawResult<Self::Output, Rect> {
+        self.error()?;
+        let pos = self.catch(justification.get(self, &(1, 1)))?;
+        self.text_absolute(&pos, string)
+    }
+    /// Writes some text on the canvas aligned around the point given by `justification`
+    ///
+    /// The point is where a single character would be placed using `justification`, see
+    /// [`Canvas::text_left`] and [`Canvas::text_right`]
+    ///
+    /// # Errors
+    ///
+    /// - If there isn't enough space
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(9, 3));
+    /// canvas.text_aligned(&Just::CenterLeft, TextAlign::Left, "foo")?;
+    /// canvas.text_aligned(&Just::CenterRight, TextAlign::Right, "bar")?;
+    ///
+    /// // .........
+    /// // .foo.bar.
+    /// // .........
+    /// assert_eq!(canvas.get(&(1, 1))?.text, 'f');
+    /// assert_eq!(canvas.get(&(7, 1))?.text, 'r');
+    /// # Ok(()) }
+    /// ```
+    fn text_aligned(&mut self, justification: &Just, align: TextAlign, string: &str) -> DrawResult<Self::Output, Rect> {
+        match align {
+            TextAlign::Left => self.text_left(justification, string),
+            TextAlign::Right => self.text_right(justification, string),
+            TextAlign::Center => {
+                self.error()?;
+                let len = self.catch(display_width(string))?;
+                let pos = self.catch(justification.get(self, &(1, 1)))?;
+                self.text_absolute(&pos.sub_x((len - 1).max(0) / 2), string)
+            },
+        }
+    }
+    /// Writes each of `items` on its own row starting at `start_y`, each ending at column `x`
+    ///
+    /// Useful for columns of numbers in a table, see [`Canvas::text_right`]
+    ///
+    /// # Errors
+    ///
+    /// - If there isn't enough space
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(5, 4));
+    /// canvas.text_column(3, 1, &[5, 10, 200], None, None)?;
+    ///
+    /// // .....
+    /// // ...5.
+    /// // ..10.
+    /// // .200.
+    /// assert_eq!(canvas.get(&(3, 1))?.text, '5');
+    /// assert_eq!(canvas.get(&(2, 2))?.text, '1');
+    /// assert_eq!(canvas.get(&(1, 3))?.text, '2');
+    /// # Ok(()) }
+    /// ```
+    fn text_column(
+        &mut self,
+        x: isize,
+        start_y: isize,
+        items: &[impl ToString],
+        foreground: impl Into<Option<Color>>,
+        background: impl Into<Option<Color>>,
+    ) -> Result<(), Error> {
+        let canvas = self.base_canvas()?;
+        let foreground = foreground.into();
+        let background = background.into();
+        for (item, y) in items.iter().zip(start_y..) {
+            canvas.text_right(&Just::At(Vec2::new(x, y)), &item.to_string())
+                .colored(foreground, background)?;
+        }
+        Ok(())
+    }
+    /// Writes some text on the canvas at `pos`
+    ///
+    /// Double-width characters (such as CJK or emoji) take up two cells, with the second filled
+    /// with [`WIDE_CHAR_PLACEHOLDER`]
+    ///
+    /// # Errors
+    ///
+    /// - If there isn't enough space
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// use canvas_tui::canvas::WIDE_CHAR_PLACEHOLDER;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(5, 3));
+    /// canvas.text_absolute(&(0, 1), "hello")?;
+    /// canvas.text_absolute(&(0, 2), "日本")?;
+    ///
+    /// // .....
+    /// // hello
+    /// // 日本.
+    /// assert_eq!(canvas.get(&(1, 1))?.text, 'e');
+    /// assert_eq!(canvas.get(&(1, 2))?.text, WIDE_CHAR_PLACEHOLDER);
+    /// assert_eq!(canvas.get(&(2, 2))?.text, '本');
+    /// # Ok(()) }
+    /// ```
+    fn text_absolute(&mut self, pos: &impl Pos, string: &str) -> DrawResult<Self::Output, Rect> {
+        let canvas = self.base_canvas()?;
+
+        let canvas_size = Vec2::from_size(canvas);
+        let pos = Vec2::from_pos(pos);
+        let mut column = 0;
+        for chr in string.chars() {
+            let width = char_width(chr);
+            for (offset, chr) in (0..width).zip([chr, WIDE_CHAR_PLACEHOLDER]) {
+                let charpos = pos.add_x(column + offset);
+                catch!(canvas.set_without_catch(charpos, chr)
+                    // add a nice error
+                    .map_err(|_| Error::TextOverflow { starting: pos, text: string.to_owned(), ending: charpos, canvas: canvas_size })
+                );
+            }
+            column += width;
+        }
+
+        Ok(DrawInfo::rect(canvas, pos, Vec2::new(column, 1)))
+    }
+    /// Writes some text on the canvas at `pos`, using `mode` to decide what happens if it runs
+    /// past the right edge of the canvas
+    ///
+    /// # Errors
+    ///
+    /// - If there isn't enough space with [`TextOverflowMode::Error`]
+    /// - If `pos` is out of bounds
+    /// - If the text runs out of rows with [`TextOverflowMode::Wrap`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(4, 3));
+    /// canvas.text_absolute_with_mode(&(1, 0), "hello", TextOverflowMode::Truncate)?;
+    /// let info = canvas.text_absolute_with_mode(&(1, 1), "hello", TextOverflowMode::Wrap)?;
+    /// assert_eq!(info.shape.size, (3, 2));
+    ///
+    /// // .hel
+    /// // .hel
+    /// // .lo.
+    /// assert_eq!(canvas.get(&(3, 0))?.text, 'l');
+    /// assert_eq!(canvas.get(&(2, 2))?.text, 'o');
+    /// # Ok(()) }
+    /// ```
+    fn text_absolute_with_mode(&mut self, pos: &impl Pos, string: &str, mode: TextOverflowMode) -> DrawResult<Self::Output, Rect> {
+        if mode == TextOverflowMode::Error {
+            return self.text_absolute(pos, string);
+        }
+
+        let canvas = self.base_canvas()?;
+
+        let canvas_size = Vec2::from_size(canvas);
+        let pos = Vec2::from_pos(pos);
+        let in_bounds = if pos.x < 0 || pos.y < 0 || pos.x >= canvas_size.x || pos.y >= canvas_size.y {
+            Err(Error::OutOfBounds(pos.x, pos.y))
+        } else {
+            Ok(())
+        };
+        canvas.catch(in_bounds)?;
+
+        let available = canvas_size.x - pos.x;
+        let mut cursor = Vec2::new(0, 0);
+        let mut width = 0;
+        for chr in string.chars() {
+            let chr_width = char_width(chr);
+            if cursor.x + chr_width > available {
+                match mode {
+                    TextOverflowMode::Wrap if cursor.x > 0 => cursor = Vec2::new(0, cursor.y + 1),
+                    _ => break,
+                }
+            }
+            for (offset, chr) in (0..chr_width).zip([chr, WIDE_CHAR_PLACEHOLDER]) {
+                let charpos = pos + cursor.add_x(offset);
+                catch!(canvas.set_without_catch(charpos, chr)
+                    .map_err(|_| Error::TextOverflow { starting: pos, text: string.to_owned(), ending: charpos, canvas: canvas_size })
+                );
+            }
+            cursor = cursor.add_x(chr_width);
+            width = width.max(cursor.x);
+        }
+
+        Ok(DrawInfo::rect(canvas, pos, Vec2::new(width, cursor.y + 1)))
+    }
+    /// Draws a box onto the canvas using `justification` with size `size`
+    ///
+    /// See [`DrawResultMethods::draw_inside`] to draw on the inside of the rect
+    ///
+    /// # Errors
+    ///
+    /// - If there isn't enough space
+    ///
+    /// # Example
+    /// 
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(5, 5));
+    /// canvas.rect(&Just::Centered, &(3, 3), &box_chars::LIGHT)?;
+    ///
+    /// // .....
+    /// // .┌─┐.
+    /// // .│.│.
+    /// // .└─┘.
+    /// // .....
+    /// assert_eq!(canvas.get(&(1, 1))?.text, '┌');
+    /// assert_eq!(canvas.get(&(2, 1))?.text, '─');
+    /// # Ok(()) }
+    /// ```
+    fn rect(&mut self, justification: &Just, size: &impl Size, chars: &'static box_chars::Chars) -> DrawResult<Self::Output, Rect> {
+        self.error()?;
+        let pos = self.catch(justification.get(self, size))?;
+        self.rect_absolute(&pos, size, chars)
+    }
+    /// Draws a box onto the canvas at `pos` with size `size`
+    ///
+    /// See [`DrawResultMethods::draw_inside`] to draw on the inside of the rect
+    ///
+    /// # Errors
+    ///
+    /// - If there isn't enough space
+    ///
+    /// # Example
+    /// 
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(5, 5));
+    /// canvas.rect_absolute(&(1, 1), &(3, 3), &box_chars::LIGHT)?;
+    ///
+    /// // .....
+    /// // .┌─┐.
+    /// // .│.│.
+    /// // .└─┘.
+    /// // .....
+    /// assert_eq!(canvas.get(&(1, 1))?.text, '┌');
+    /// assert_eq!(canvas.get(&(2, 1))?.text, '─');
+    /// # Ok(()) }
+    /// ```
+    fn rect_absolute(&mut self, pos: &impl Pos, size: &impl Size, chars: &'static box_chars::Chars) -> DrawResult<Self::Output, Rect> {
+        let canvas = self.base_canvas()?;
+
+        let size = Vec2::from_size(size);
+        let pos = Vec2::from_pos(pos);
+        canvas.catch(check_bounds(pos, size, canvas, "rect"))?;
+
+        let top = 0;
+        let bottom = size.height() - 1;
+        let left = 0;
+        let right = size.width() - 1;
+
+        for x in (left + 1)..right {
+            canvas.set(&(pos + (x, top)), chars.horizontal())?;
+            canvas.set(&(pos + (x, bottom)), chars.horizontal())?;
+        }
+
+        for y in (top + 1)..bottom {
+            canvas.set(&(pos + (left, y)), chars.vertical())?;
+            canvas.set(&(pos + (right, y)), chars.vertical())?;
+        }
+
+        // set corners                             udlr
+        canvas.set(&(pos + (left, top)),     chars[0b0101])?;
+        canvas.set(&(pos + (right, top)),    chars[0b0110])?;
+        canvas.set(&(pos + (left, bottom)),  chars[0b1001])?;
+        canvas.set(&(pos + (right, bottom)), chars[0b1010])?;
+
+        Ok(DrawInfo::rect(canvas, pos, size))
+    }
+    /// Draws a box onto the canvas using `justification` with size `size`, filling the inside with `fill`
+    ///
+    /// Equivalent to [`canvas.rect(justification, size, chars)`](Self::rect)[`.fill_inside(fill)`](DrawResultMethods::fill_inside)
+    ///
+    /// # Errors
+    ///
+    /// - If there isn't enough space
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::filled_with_text(&(5, 5), '.');
+    /// canvas.rect_filled(&Just::Centered, &(3, 3), &box_chars::LIGHT, ' ')?;
+    ///
+    /// // .....
+    /// // .┌─┐.
+    /// // .│ │.
+    /// // .└─┘.
+    /// // .....
+    /// assert_eq!(canvas.get(&(1, 1))?.text, '┌');
+    /// assert_eq!(canvas.get(&(2, 2))?.text, ' ');
+    /// # Ok(()) }
+    /// ```
+    fn rect_filled(
+        &mut self,
+        justification: &Just,
+        size: &impl Size,
+        chars: &'static box_chars::Chars,
+        fill: char
+    ) -> DrawResult<Self::Output, Rect> {
+        self.rect(justification, size, chars).fill_inside(fill)
+    }
+    /// Highlights the inside of a frame of size `size` placed using `justification` with
+    /// `background`, leaving the border untouched
+    ///
+    /// Returns the inside of the frame
+    ///
+    /// # Errors
+    ///
+    /// - If there isn't enough space
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(5, 5));
+    /// canvas.rect(&Just::Centered, &(3, 3), &box_chars::LIGHT)?;
+    /// canvas.text(&Just::Centered, "a").foreground(Color::WHITE)?;
+    /// canvas.frame_bg(&Just::Centered, &(3, 3), Color::new(0, 0, 0))?;
+    ///
+    /// // .....
+    /// // .┌─┐.
+    /// // .│-│. (highlight represented by -)
+    /// // .└─┘.
+    /// // .....
+    /// assert_eq!(canvas.get(&(2, 2))?.background, Some(Color::new(0, 0, 0)));
+    /// // the foreground is left alone
+    /// assert_eq!(canvas.get(&(2, 2))?.foreground, Some(Color::WHITE));
+    /// assert_eq!(canvas.get(&(1, 1))?.background, None);
+    /// # Ok(()) }
+    /// ```
+    fn frame_bg(&mut self, justification: &Just, size: &impl Size, background: impl Into<ColorUpdate>) -> DrawResult<Self::Output, Rect> {
+        self.error()?;
+        let pos = self.catch(justification.get(self, size))?;
+        self.highlight_box(&(pos + 1), &(Vec2::from_size(size) - 2), ColorUpdate::Keep, background)
+    }
+    /// Draws a box onto the canvas with justification `just`, grid dimensions `dims`, cell size
+    /// `cell_size`, and using box chars `chars` 
+    ///
+    /// See [`DrawResultMethods::draw_inside`] to draw on the inside of the grid
     ///
     /// # Errors
     ///
@@ -588,20 +1362,102 @@ pub trait Canvas : Size + Sized {
     /// assert_eq!(canvas.get(&(4, 3))?.text, '┼');
     /// # Ok(()) }
     /// ```
-    fn grid_absolute(
+    fn grid_absolute(
+        &mut self,
+        pos: &impl Pos,
+        cell_size: &impl Size,
+        dims: &impl Size,
+        chars: &'static box_chars::Chars
+    ) -> DrawResult<Self::Output, Grid> {
+        let canvas = self.base_canvas()?;
+
+        let pos = Vec2::from_pos(pos);
+        let cell_size = Vec2::from_size(cell_size);
+        let dims = Vec2::from_size(dims);
+        let full_size = full_grid_size(cell_size, dims);
+        canvas.catch(check_bounds(pos, full_size, canvas, "grid"))?;
+
+        let top = 0;
+        let bottom = full_size.height() - 1;
+        let left = 0;
+        let right = full_size.width() - 1;
+
+        // outer rectangle
+        canvas.rect_absolute(&pos, &full_size, chars)?;
+
+        // middle horizontal lines
+        for horizontal in 1..dims.y {
+            let y = horizontal * (cell_size.y + 1);
+            canvas.set(&(pos + (left, y)), chars[0b1101])?;
+            canvas.set(&(pos + (right, y)), chars[0b1110])?;
+            for x in (left + 1)..right {
+                canvas.set(&(pos + (x, y)), chars.horizontal())?;
+            }
+        }
+
+        // middle vertical lines
+        for vertical in 1..dims.x {
+            let x = vertical * (cell_size.x + 1);
+            canvas.set(&(pos + (x, top)), chars[0b0111])?;
+            canvas.set(&(pos + (x, bottom)), chars[0b1011])?;
+            for y in (top + 1)..bottom {
+                canvas.set(&(pos + (x, y)), chars.vertical())?;
+            }
+        }
+
+        // intersections
+        for intersection in dims - 1 {
+            let pos = pos + (intersection + 1) * (cell_size + 1);
+            canvas.set(&pos, chars[0b1111])?;
+        }
+
+        // the grid returned fills up the entire grid including the outlines
+        // so there's some overlap
+        Ok(DrawInfo::grid(canvas, pos + 1, dims, cell_size + 2, Vec2::new(-1, -1)))
+    }
+    /// Draws a grid onto the canvas starting at `pos` where each column is as wide as the
+    /// corresponding item in `col_widths` and each row is as tall as the corresponding item in
+    /// `row_heights`, using box chars `chars`
+    ///
+    /// See [`Canvas::grid_absolute`] for a grid where every cell is the same size
+    ///
+    /// # Errors
+    ///
+    /// - If there isn't enough space
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(8, 6));
+    /// canvas.variable_grid_absolute(&(0, 0), &[1, 3], &[1, 2], &box_chars::LIGHT)?;
+    ///
+    /// // ┌─┬───┐.
+    /// // │.│...│.
+    /// // ├─┼───┤.
+    /// // │.│...│.
+    /// // │.│...│.
+    /// // └─┴───┘.
+    /// assert_eq!(canvas.get(&(2, 0))?.text, '┬');
+    /// assert_eq!(canvas.get(&(2, 2))?.text, '┼');
+    /// assert_eq!(canvas.get(&(6, 5))?.text, '┘');
+    /// # Ok(()) }
+    /// ```
+    fn variable_grid_absolute(
         &mut self,
         pos: &impl Pos,
-        cell_size: &impl Size,
-        dims: &impl Size,
+        col_widths: &[isize],
+        row_heights: &[isize],
         chars: &'static box_chars::Chars
-    ) -> DrawResult<Self::Output, Grid> {
+    ) -> DrawResult<Self::Output, VariableGrid> {
         let canvas = self.base_canvas()?;
 
         let pos = Vec2::from_pos(pos);
-        let cell_size = Vec2::from_size(cell_size);
-        let dims = Vec2::from_size(dims);
-        let full_size = full_grid_size(cell_size, dims);
-        canvas.catch(check_bounds(pos, full_size, canvas, "grid"))?;
+        // the grid with a spacing of 1 for the lines between each cell
+        let grid = VariableGrid { pos, col_widths: col_widths.to_vec(), row_heights: row_heights.to_vec(), spacing: Vec2::ONE };
+        let full_size = grid.full_size();
+        canvas.catch(check_bounds(pos, full_size, canvas, "variable grid"))?;
 
         let top = 0;
         let bottom = full_size.height() - 1;
@@ -612,8 +1468,8 @@ pub trait Canvas : Size + Sized {
         canvas.rect_absolute(&pos, &full_size, chars)?;
 
         // middle horizontal lines
-        for horizontal in 1..dims.y {
-            let y = horizontal * (cell_size.y + 1);
+        let horizontals = inner_lines(row_heights);
+        for &y in &horizontals {
             canvas.set(&(pos + (left, y)), chars[0b1101])?;
             canvas.set(&(pos + (right, y)), chars[0b1110])?;
             for x in (left + 1)..right {
@@ -622,8 +1478,8 @@ pub trait Canvas : Size + Sized {
         }
 
         // middle vertical lines
-        for vertical in 1..dims.x {
-            let x = vertical * (cell_size.x + 1);
+        let verticals = inner_lines(col_widths);
+        for &x in &verticals {
             canvas.set(&(pos + (x, top)), chars[0b0111])?;
             canvas.set(&(pos + (x, bottom)), chars[0b1011])?;
             for y in (top + 1)..bottom {
@@ -632,14 +1488,12 @@ pub trait Canvas : Size + Sized {
         }
 
         // intersections
-        for intersection in dims - 1 {
-            let pos = pos + (intersection + 1) * (cell_size + 1);
-            canvas.set(&pos, chars[0b1111])?;
+        for (&x, &y) in iproduct!(&verticals, &horizontals) {
+            canvas.set(&(pos + (x, y)), chars[0b1111])?;
         }
 
-        // the grid returned fills up the entire grid including the outlines
-        // so there's some overlap
-        Ok(DrawInfo::grid(canvas, pos + 1, dims, cell_size + 2, Vec2::new(-1, -1)))
+        // like grid_absolute, the grid returned fills up the entire grid including the outlines
+        Ok(DrawInfo::new(canvas, grid.grow(&(1, 1))))
     }
     /// Prints the canvas without color to stdout
     ///
@@ -674,6 +1528,225 @@ pub trait Canvas : Size + Sized {
         }
         Ok(())
     }
+    /// Prints the canvas with color to stdout with its top left corner at `terminal_pos`
+    ///
+    /// The cursor is moved to the start of each row before it's printed, so the rest of the
+    /// terminal is left untouched. `terminal_pos` starts at (0, 0) in the top left of the terminal
+    ///
+    /// # Errors
+    ///
+    /// - If the canvas has an outstading error (see [`DrawResult`])
+    fn print_at(&self, terminal_pos: Vec2) -> Result<(), Error> {
+        use std::io::Write;
+
+        self.error()?;
+        let canvas = Vec2::from_size(self);
+        for y in 0..canvas.height() {
+            // ansi positions start at 1
+            print!("\x1B[{};{}H", terminal_pos.y + y + 1, terminal_pos.x + 1);
+            for x in 0..canvas.width() {
+                let cell = self.get(&(x, y)).expect("in-bounds get to not fail");
+                print!("{}", Color::paint(cell.text, cell.foreground, cell.background));
+            }
+        }
+        // nothing ends in a newline, so stdout has to be flushed manually
+        std::io::stdout().flush().expect("stdout to be flushable");
+        Ok(())
+    }
+    /// Clears the terminal, then prints the canvas with color to stdout from the top left corner
+    ///
+    /// # Errors
+    ///
+    /// - If the canvas has an outstading error (see [`DrawResult`])
+    fn print_clear_screen(&self) -> Result<(), Error> {
+        self.error()?;
+        // clear the screen and move the cursor home
+        print!("\x1B[2J\x1B[H");
+        self.print()
+    }
+    /// Prints the canvas with color to stdout, then resets the terminal's colors and styles
+    ///
+    /// This makes sure nothing printed after the canvas inherits its colors
+    ///
+    /// # Errors
+    ///
+    /// - If the canvas has an outstading error (see [`DrawResult`])
+    fn print_ansi_reset(&self) -> Result<(), Error> {
+        use std::io::Write;
+
+        self.print()?;
+        print!("\x1B[0m");
+        std::io::stdout().flush().expect("stdout to be flushable");
+        Ok(())
+    }
+    /// Prints the canvas with color to stdout, surrounded by dimmed coordinates for debugging layouts
+    ///
+    /// The first row shows the last digit of each column's x, and each row starts with its y
+    ///
+    /// ```text
+    ///   01234
+    /// 0 ·····
+    /// 1 ·foo·
+    /// 2 ·····
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - If the canvas has an outstading error (see [`DrawResult`])
+    fn print_debug(&self) -> Result<(), Error> {
+        const DIM: &str = "\x1B[2m";
+        const RESET: &str = "\x1B[0m";
+
+        self.error()?;
+        let canvas = Vec2::from_size(self);
+        let label_width = (canvas.height() - 1).max(0).to_string().len();
+
+        let columns: String = (0..canvas.width()).map(|x| (x % 10).to_string()).collect();
+        println!("{DIM}{:label_width$} {columns}{RESET}", "");
+        for y in 0..canvas.height() {
+            print!("{DIM}{y:>label_width$}{RESET} ");
+            for x in 0..canvas.width() {
+                let cell = self.get(&(x, y)).expect("in-bounds get to not fail");
+                print!("{}", Color::paint(cell.text, cell.foreground, cell.background));
+            }
+            println!();
+        }
+        Ok(())
+    }
+    /// Enables raw mode, clears the terminal, then prints the canvas with color to stdout from the
+    /// top left corner
+    ///
+    /// Raw mode is left enabled, so it should be disabled with
+    /// `crossterm::terminal::disable_raw_mode` before the program exits
+    ///
+    /// # Errors
+    ///
+    /// - If the canvas has an outstading error (see [`DrawResult`])
+    /// - If raw mode couldn't be enabled
+    #[cfg(feature = "crossterm")]
+    fn print_raw_mode(&self) -> Result<(), Error> {
+        self.error()?;
+        crossterm::terminal::enable_raw_mode().map_err(|err| Error::RawMode(err.to_string()))?;
+        print!("\x1B[2J");
+        // newlines don't return the cursor to the start of the line in raw mode,
+        // so each row has to be positioned manually
+        self.print_at(Vec2::new(0, 0))
+    }
+    /// Renders the canvas as an html `<pre>` block, coloring it with inline styles
+    ///
+    /// Consecutive cells with the same colors are grouped into a single `<span>`
+    ///
+    /// # Errors
+    ///
+    /// - If the canvas has an outstading error (see [`DrawResult`])
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(4, 1));
+    /// canvas.text(&Just::CenteredOnRow(0), "<>").background(Color::new(255, 0, 0))?;
+    ///
+    /// assert_eq!(
+    ///     canvas.export_html()?,
+    ///     "<pre> <span style=\"background-color:#ff0000;\">&lt;&gt;</span> </pre>"
+    /// );
+    /// # Ok(()) }
+    /// ```
+    fn export_html(&self) -> Result<String, Error> {
+        self.error()?;
+        let canvas = Vec2::from_size(self);
+        let mut html = String::from("<pre>");
+        for y in 0..canvas.height() {
+            if y != 0 { html.push('\n'); }
+
+            let mut run = String::new();
+            let mut run_colors = (None, None);
+            for x in 0..canvas.width() {
+                let cell = self.get(&(x, y)).expect("in-bounds get to not fail");
+                // the wide character before it already covers this cell
+                if cell.text == WIDE_CHAR_PLACEHOLDER { continue; }
+                let colors = (cell.foreground, cell.background);
+                // end the run once the colors change
+                if colors != run_colors {
+                    html_run(&mut html, &run, run_colors);
+                    run.clear();
+                    run_colors = colors;
+                }
+                push_escaped(&mut run, cell.text);
+            }
+            html_run(&mut html, &run, run_colors);
+        }
+        html.push_str("</pre>");
+        Ok(html)
+    }
+    /// Renders the canvas as an svg image
+    ///
+    /// Each cell takes up `char_width` by `char_height`, with backgrounds drawn as `<rect>`s and
+    /// characters as `<text>` using a monospace font of size `font_size`
+    ///
+    /// # Errors
+    ///
+    /// - If the canvas has an outstading error (see [`DrawResult`])
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(3, 1));
+    /// canvas.text(&Just::Centered, "a").background(Color::new(255, 0, 0))?;
+    ///
+    /// let svg = canvas.export_svg(10.0, 6.0, 12.0)?;
+    /// assert!(svg.starts_with("<svg"));
+    /// assert!(svg.contains("<rect x=\"6\" y=\"0\" width=\"6\" height=\"12\" fill=\"#ff0000\"/>"));
+    /// assert!(svg.contains(">a</text>"));
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    fn export_svg(&self, font_size: f32, char_width: f32, char_height: f32) -> Result<String, Error> {
+        self.error()?;
+        let canvas = Vec2::from_size(self);
+        let mut backgrounds = String::new();
+        let mut text = String::new();
+        for y in 0..canvas.height() {
+            let top = y as f32 * char_height;
+            // put the baseline near the bottom of the cell to leave room for descenders
+            let baseline = top + char_height * 0.8;
+
+            // group backgrounds into runs of the same color
+            let mut run_start = 0;
+            let mut run_color = None;
+            for x in 0..=canvas.width() {
+                let cell = (x < canvas.width()).then(|| self.get(&(x, y)).expect("in-bounds get to not fail"));
+                let background = cell.as_ref().and_then(|cell| cell.background);
+                if x == canvas.width() || background != run_color {
+                    if let Some(color) = run_color {
+                        backgrounds.push_str(&format!(
+                            "<rect x=\"{}\" y=\"{top}\" width=\"{}\" height=\"{char_height}\" fill=\"{}\"/>",
+                            run_start as f32 * char_width, (x - run_start) as f32 * char_width, css_color(color),
+                        ));
+                    }
+                    run_start = x;
+                    run_color = background;
+                }
+
+                let Some(cell) = cell else { continue };
+                if cell.text == ' ' || cell.text == WIDE_CHAR_PLACEHOLDER { continue; }
+                let mut escaped = String::new();
+                push_escaped(&mut escaped, cell.text);
+                let fill = cell.foreground.map_or_else(String::new, |color| format!(" fill=\"{}\"", css_color(color)));
+                text.push_str(&format!("<text x=\"{}\" y=\"{baseline}\"{fill}>{escaped}</text>", x as f32 * char_width));
+            }
+        }
+
+        Ok(format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-family=\"monospace\" font-size=\"{font_size}\">{backgrounds}{text}</svg>",
+            canvas.width() as f32 * char_width,
+            canvas.height() as f32 * char_height,
+        ))
+    }
     /// Fills the canvas with `chr`
     ///
     /// # Errors
@@ -758,6 +1831,94 @@ impl Basic {
             background: Array2D::filled_with(background.into(), width, height),
         }
     }
+
+    /// Changes the size of the canvas to `size`, keeping everything that still fits
+    ///
+    /// Any new cells are filled with `chr`, `foreground`, and `background`
+    ///
+    /// # Panics
+    ///
+    /// - If `size` is negative
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(3, 3));
+    /// canvas.set(&(1, 1), 'a')?;
+    /// canvas.resize(&(5, 2), '.', None, None);
+    ///
+    /// // ...   .....
+    /// // .a. → .a...
+    /// // ...
+    /// assert_eq!(canvas.get(&(1, 1))?.text, 'a');
+    /// assert_eq!(canvas.get(&(4, 1))?.text, '.');
+    /// assert!(canvas.get(&(1, 2)).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn resize(
+        &mut self,
+        size: &impl Size,
+        chr: char,
+        foreground: impl Into<Option<Color>>,
+        background: impl Into<Option<Color>>,
+    ) {
+        let mut resized = Self::filled_with(size, chr, foreground, background);
+
+        // copy over the region both canvases share
+        let width = self.text.num_rows().min(resized.text.num_rows());
+        let height = self.text.num_columns().min(resized.text.num_columns());
+        for pos in iproduct!(0..width, 0..height) {
+            resized.text[pos] = self.text[pos];
+            resized.foreground[pos] = self.foreground[pos];
+            resized.background[pos] = self.background[pos];
+        }
+
+        *self = resized;
+    }
+
+    /// Copies every cell of `source` onto the canvas with its top left corner at `pos`
+    ///
+    /// Any colors `source` doesn't have ([`None`]) are transparent, keeping the colors underneath
+    /// like [`ColorUpdate::Keep`]
+    ///
+    /// # Errors
+    ///
+    /// - If `source` doesn't fit on the canvas at `pos`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::filled_with(&(4, 3), '.', None, Color::BLACK);
+    /// let label = Basic::filled_with(&(2, 1), 'a', Color::WHITE, None);
+    /// canvas.blit(&(1, 1), &label)?;
+    ///
+    /// // ....
+    /// // .aa.
+    /// // ....
+    /// assert_eq!(canvas.get(&(2, 1))?.text, 'a');
+    /// assert_eq!(canvas.get(&(2, 1))?.foreground, Some(Color::WHITE));
+    /// assert_eq!(canvas.get(&(2, 1))?.background, Some(Color::BLACK));
+    /// # Ok(()) }
+    /// ```
+    pub fn blit(&mut self, pos: &impl Pos, source: &impl Canvas) -> Result<(), Error> {
+        let pos = Vec2::from_pos(pos);
+        let size = Vec2::from_size(source);
+        if pos.x < 0 || pos.y < 0 { return Err(Error::OutOfBounds(pos.x, pos.y)); }
+        check_bounds(pos, size, self, "blit")?;
+
+        for (x, y) in iproduct!(0..size.x, 0..size.y) {
+            let cell = source.get(&(x, y))?;
+            let target = pos + (x, y);
+            self.set_without_catch(target, cell.text)?;
+            let transparent = |color: Option<Color>| color.map_or(ColorUpdate::Keep, ColorUpdate::from);
+            self.highlight_without_catch(target, transparent(cell.foreground), transparent(cell.background))?;
+        }
+        Ok(())
+    }
 }
 
 impl Size for Basic {
@@ -775,16 +1936,20 @@ impl Canvas for Basic {
         Ok(self)
     }
 
-    fn highlight_without_catch(&mut self, pos: Vec2, foreground: Option<Color>, background: Option<Color>) -> Result<&mut Self, Error> {
+    fn highlight_without_catch(&mut self, pos: Vec2, foreground: ColorUpdate, background: ColorUpdate) -> Result<&mut Self, Error> {
         let (x, y) = pos.try_into().map_err(|_| Error::OutOfBounds(pos.x, pos.y))?;
-        if matches!(foreground, Some(_)) { self.foreground.set(x, y, foreground).map_err(|_| Error::OutOfBounds(pos.x, pos.y))?; }
-        if matches!(background, Some(_)) { self.background.set(x, y, background).map_err(|_| Error::OutOfBounds(pos.x, pos.y))?; }
+        if let ColorUpdate::Set(foreground) = foreground {
+            self.foreground.set(x, y, foreground).map_err(|_| Error::OutOfBounds(pos.x, pos.y))?;
+        }
+        if let ColorUpdate::Set(background) = background {
+            self.background.set(x, y, background).map_err(|_| Error::OutOfBounds(pos.x, pos.y))?;
+        }
         Ok(self)
     }
 
     fn get(&self, pos: &impl Pos) -> Result<Cell, Error> {
         let pos = Vec2::from_pos(pos);
-        if pos.x > self.dims.width() || pos.y > self.dims.height() {
+        if pos.x >= self.dims.width() || pos.y >= self.dims.height() {
             return Err(Error::OutOfBounds(pos.x, pos.y));
         }
         let pos = pos.try_into()?;
@@ -850,8 +2015,8 @@ impl<'a, C: Canvas> Canvas for Window<'a, C> {
     fn highlight_without_catch(
         &mut self,
         pos: Vec2,
-        foreground: Option<Color>,
-        background: Option<Color>
+        foreground: ColorUpdate,
+        background: ColorUpdate
     ) -> Result<&mut Self, Error> {
         match self.canvas.highlight_without_catch(pos + self.offset, foreground, background) {
             Ok(_) => Ok(self),
@@ -864,7 +2029,10 @@ impl<'a, C: Canvas> Canvas for Window<'a, C> {
     }
 
     fn window_absolute(&mut self, pos: &impl Pos, size: &impl Size) -> Result<Self::Window<'_>, Error> {
-        Ok(Window::new(self.canvas, &(Vec2::from_pos(pos) + self.offset), size))
+        // make sure the window fits inside this one
+        let pos = Vec2::from_pos(pos);
+        self.catch(check_window(pos, Vec2::from_size(size), self))?;
+        Ok(Window::new(self.canvas, &(pos + self.offset), size))
     }
 
     fn error(&self) -> Result<(), Error> { Ok(()) }
@@ -897,8 +2065,8 @@ impl<C: Canvas, F: Fn(&mut C, &Error) -> Result<(), Error>> Canvas for ErrorCatc
     fn highlight_without_catch(
         &mut self,
         pos: Vec2,
-        foreground: Option<Color>,
-        background: Option<Color>
+        foreground: ColorUpdate,
+        background: ColorUpdate
     ) -> Result<&mut Self::Output, Error> {
         self.canvas.highlight_without_catch(pos, foreground, background)?;
         Ok(self)
@@ -920,6 +2088,79 @@ impl<C: Canvas, F: Fn(&mut C, &Error) -> Result<(), Error>> Canvas for ErrorCatc
     fn base_canvas(&mut self) -> Result<&mut Self::Output, Error> { Ok(self) }
 }
 
+/// A canvas with its type erased, so it can be drawn on without knowing the underlying canvas
+///
+/// This is what makes `dyn` [widgets](Widget) possible, see [`widgets::DynWidget`]
+pub struct DynCanvas<'a> {
+    canvas: &'a mut dyn ErasedCanvas,
+}
+
+impl<'a> DynCanvas<'a> {
+    pub fn new(canvas: &'a mut impl Canvas) -> Self {
+        Self { canvas }
+    }
+}
+
+/// The object-safe parts of [`Canvas`] needed for [`DynCanvas`]
+trait ErasedCanvas {
+    fn erased_size(&self) -> Vec2;
+    fn erased_set(&mut self, pos: Vec2, chr: char) -> Result<(), Error>;
+    fn erased_highlight(&mut self, pos: Vec2, foreground: ColorUpdate, background: ColorUpdate) -> Result<(), Error>;
+    fn erased_get(&self, pos: Vec2) -> Result<Cell, Error>;
+    fn erased_error(&self) -> Result<(), Error>;
+    fn erased_throw(&mut self, err: &Error);
+}
+
+impl<C: Canvas> ErasedCanvas for C {
+    fn erased_size(&self) -> Vec2 { Vec2::from_size(self) }
+    fn erased_set(&mut self, pos: Vec2, chr: char) -> Result<(), Error> {
+        self.set_without_catch(pos, chr).map(discard_reference)
+    }
+    fn erased_highlight(&mut self, pos: Vec2, foreground: ColorUpdate, background: ColorUpdate) -> Result<(), Error> {
+        self.highlight_without_catch(pos, foreground, background).map(discard_reference)
+    }
+    fn erased_get(&self, pos: Vec2) -> Result<Cell, Error> { self.get(&pos) }
+    fn erased_error(&self) -> Result<(), Error> { self.error() }
+    fn erased_throw(&mut self, err: &Error) { self.throw(err) }
+}
+
+impl<'a> Size for DynCanvas<'a> {
+    fn width(&self) -> isize { self.canvas.erased_size().width() }
+    fn height(&self) -> isize { self.canvas.erased_size().height() }
+}
+
+impl<'a> Canvas for DynCanvas<'a> {
+    type Output = Self;
+    type Window<'w> = Window<'w, Self> where Self: 'w;
+
+    fn set_without_catch(&mut self, pos: Vec2, chr: char) -> Result<&mut Self::Output, Error> {
+        self.canvas.erased_set(pos, chr)?;
+        Ok(self)
+    }
+
+    fn highlight_without_catch(
+        &mut self,
+        pos: Vec2,
+        foreground: ColorUpdate,
+        background: ColorUpdate
+    ) -> Result<&mut Self::Output, Error> {
+        self.canvas.erased_highlight(pos, foreground, background)?;
+        Ok(self)
+    }
+
+    fn get(&self, pos: &impl Pos) -> Result<Cell, Error> { self.canvas.erased_get(Vec2::from_pos(pos)) }
+
+    fn window_absolute(&mut self, pos: &impl Pos, size: &impl Size) -> Result<Self::Window<'_>, Error> {
+        // the erased canvas could be a window itself, so this has to fit inside of it
+        self.catch(check_window(Vec2::from_pos(pos), Vec2::from_size(size), self))?;
+        Ok(Window::new(self, pos, size))
+    }
+
+    fn error(&self) -> Result<(), Error> { self.canvas.erased_error() }
+    fn throw(&mut self, err: &Error) { self.canvas.erased_throw(err) }
+    fn base_canvas(&mut self) -> Result<&mut Self::Output, Error> { Ok(self) }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -948,6 +2189,125 @@ mod test {
         });
     }
 
+    #[test]
+    fn get_in_bounds() -> Result<(), Error> {
+        let mut canvas = Basic::new(&(5, 3));
+        canvas.set(&(0, 0), 'a')?;
+        canvas.set(&(4, 2), 'b')?;
+        assert_eq!(canvas.get(&(0, 0))?.text, 'a');
+        assert_eq!(canvas.get(&(4, 2))?.text, 'b');
+        Ok(())
+    }
+
+    #[test]
+    fn get_past_width() {
+        let canvas = Basic::new(&(5, 3));
+        assert!(matches!(canvas.get(&(5, 0)), Err(Error::OutOfBounds(5, 0))));
+        assert!(matches!(canvas.get(&(6, 0)), Err(Error::OutOfBounds(6, 0))));
+    }
+
+    #[test]
+    fn get_past_height() {
+        let canvas = Basic::new(&(5, 3));
+        assert!(matches!(canvas.get(&(0, 3)), Err(Error::OutOfBounds(0, 3))));
+        assert!(matches!(canvas.get(&(4, 3)), Err(Error::OutOfBounds(4, 3))));
+    }
+
+    #[test]
+    fn get_negative() {
+        let canvas = Basic::new(&(5, 3));
+        assert!(matches!(canvas.get(&(-1, 0)), Err(Error::NegativeValue { value: -1, .. })));
+        assert!(matches!(canvas.get(&(0, -1)), Err(Error::NegativeValue { value: -1, .. })));
+    }
+
+    #[test]
+    fn get_through_window() -> Result<(), Error> {
+        let mut canvas = Basic::new(&(5, 5));
+        let window = canvas.window_absolute(&(1, 1), &(3, 3))?;
+        assert!(window.get(&(2, 2)).is_ok());
+        // windows only offset their reads, so this reads from (5, 5) in the canvas
+        assert!(matches!(window.get(&(4, 4)), Err(Error::OutOfBounds(5, 5))));
+        Ok(())
+    }
+
+    #[test]
+    fn window_out_of_bounds() {
+        let mut canvas = Basic::new(&(5, 5));
+        let mut window = canvas.window_absolute(&(1, 1), &(3, 3)).expect("window fits");
+        assert!(matches!(window.window_absolute(&(1, 1), &(3, 3)), Err(Error::ItemTooBig { .. })));
+        assert!(matches!(window.window_absolute(&(-1, 0), &(2, 2)), Err(Error::OutOfBounds(-1, 0))));
+        assert!(window.window_absolute(&(1, 1), &(2, 2)).is_ok());
+    }
+
+    #[test]
+    fn dyn_window_out_of_bounds() {
+        let mut canvas = Basic::new(&(5, 5));
+        let mut window = canvas.window_absolute(&(1, 1), &(3, 3)).expect("window fits");
+        let mut canvas = DynCanvas::new(&mut window);
+        assert!(matches!(canvas.window_absolute(&(1, 1), &(3, 3)), Err(Error::ItemTooBig { .. })));
+        assert!(matches!(canvas.window_absolute(&(-1, 0), &(2, 2)), Err(Error::OutOfBounds(-1, 0))));
+        assert!(canvas.window_absolute(&(1, 1), &(2, 2)).is_ok());
+    }
+
+    #[test]
+    fn highlight_none_clears() -> Result<(), Error> {
+        let mut canvas = Basic::filled_with(&(3, 3), ' ', Color::WHITE, Color::BLACK);
+        canvas.highlight(&(1, 1), None, None)?;
+
+        assert_eq!(canvas.get(&(1, 1))?.foreground, None);
+        assert_eq!(canvas.get(&(1, 1))?.background, None);
+        // the rest of the canvas is left alone
+        assert_eq!(canvas.get(&(0, 0))?.foreground, Some(Color::WHITE));
+        assert_eq!(canvas.get(&(0, 0))?.background, Some(Color::BLACK));
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_draw_keeps_earlier_errors() {
+        let mut canvas = Basic::new(&(7, 3));
+        let mut failed = canvas.rect(&Just::Centered, &(9, 9), &box_chars::LIGHT);
+        let res = failed.try_draw(&Just::Centered, crate::widgets::basic::title("foo", None, None));
+        assert!(matches!(res, Some(Err(Error::ItemTooBig { .. } | Error::JustificationOutOfBounds { .. }))));
+    }
+
+    #[test]
+    fn exports_skip_wide_char_placeholder() -> Result<(), Error> {
+        let mut canvas = Basic::new(&(3, 1));
+        canvas.text_absolute(&(0, 0), "本a")?;
+
+        assert_eq!(canvas.export_html()?, "<pre>本a</pre>");
+        let svg = canvas.export_svg(10.0, 6.0, 12.0)?;
+        assert!(!svg.contains(WIDE_CHAR_PLACEHOLDER));
+        assert!(svg.contains(">本</text>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn any_color_can_be_stored() -> Result<(), Error> {
+        let color = Color::new(0, 0, 1);
+        let mut canvas = Basic::new(&(2, 1));
+        canvas.highlight(&(0, 0), color, color)?;
+        assert_eq!(canvas.get(&(0, 0))?.foreground, Some(color));
+        assert_eq!(canvas.get(&(0, 0))?.background, Some(color));
+
+        Ok(())
+    }
+
+    #[test]
+    fn foreground_keeps_background() -> Result<(), Error> {
+        let mut canvas = Basic::filled_with(&(3, 3), ' ', None, Color::BLACK);
+        canvas.text_absolute(&(1, 1), "a").foreground(Color::WHITE)?;
+        canvas.text_absolute(&(0, 0), "b").background(Color::WHITE)?;
+
+        assert_eq!(canvas.get(&(1, 1))?.foreground, Some(Color::WHITE));
+        assert_eq!(canvas.get(&(1, 1))?.background, Some(Color::BLACK));
+        assert_eq!(canvas.get(&(0, 0))?.background, Some(Color::WHITE));
+
+        Ok(())
+    }
+
     #[test]
     fn when_error_on_base_catches_window_error() -> Result<(), Error> {
         let mut canvas = Basic::new(&(5, 5))
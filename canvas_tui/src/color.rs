@@ -28,6 +28,209 @@ impl Color {
         Self { r, g, b }
     }
 
+    /// The ANSI escape code that sets the foreground to this color
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// assert_eq!(rgb(255, 0, 128).to_ansi_fg_code(), "\x1B[38;2;255;0;128m");
+    /// ```
+    #[must_use]
+    pub fn to_ansi_fg_code(&self) -> String {
+        format!("\x1B[38;2;{};{};{}m", self.r, self.g, self.b)
+    }
+
+    /// The ANSI escape code that sets the background to this color
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// assert_eq!(rgb(255, 0, 128).to_ansi_bg_code(), "\x1B[48;2;255;0;128m");
+    /// ```
+    #[must_use]
+    pub fn to_ansi_bg_code(&self) -> String {
+        format!("\x1B[48;2;{};{};{}m", self.r, self.g, self.b)
+    }
+
+    /// Whether the color is dark, so light text would be easier to read on top of it
+    ///
+    /// Uses the perceived luminance `0.299 * r + 0.587 * g + 0.114 * b`, which is dark below 128
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// assert!(rgb(48, 52, 70).is_dark());
+    /// assert!(!rgb(242, 213, 207).is_dark());
+    /// // green looks brighter than blue
+    /// assert!(!rgb(0, 255, 0).is_dark());
+    /// assert!(rgb(0, 0, 255).is_dark());
+    /// ```
+    #[must_use]
+    pub fn is_dark(&self) -> bool {
+        let luminance = 0.299 * f32::from(self.r) + 0.587 * f32::from(self.g) + 0.114 * f32::from(self.b);
+        luminance < 128.0
+    }
+
+    /// Whether the color is light, the opposite of [`is_dark`](Self::is_dark)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// assert!(rgb(242, 213, 207).is_light());
+    /// assert!(!Color::BLACK.is_light());
+    /// ```
+    #[must_use]
+    pub fn is_light(&self) -> bool {
+        !self.is_dark()
+    }
+
+    /// Either [white](Self::WHITE) or [black](Self::BLACK), whichever is easier to read on top of `background`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// assert_eq!(Color::auto_foreground(rgb(48, 52, 70)), Color::WHITE);
+    /// assert_eq!(Color::auto_foreground(rgb(242, 213, 207)), Color::BLACK);
+    /// ```
+    #[must_use]
+    pub fn auto_foreground(background: Self) -> Self {
+        if background.is_dark() { Self::WHITE } else { Self::BLACK }
+    }
+
+    /// Makes the color more saturated by adding `amount` to its saturation in HSV, keeping it
+    /// within `0.0..=1.0`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// assert_eq!(rgb(128, 64, 64).saturate(0.5), rgb(128, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn saturate(self, amount: f32) -> Self {
+        let (hue, saturation, value) = self.to_hsv();
+        Self::from_hsv(hue, (saturation + amount).clamp(0.0, 1.0), value)
+    }
+
+    /// Makes the color less saturated by taking `amount` from its saturation in HSV, keeping it
+    /// within `0.0..=1.0`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// assert_eq!(rgb(128, 64, 64).desaturate(1.0), Color::grayscale(128));
+    /// ```
+    #[must_use]
+    pub fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// Mixes the color towards the gray with the same average brightness, where `amount` is
+    /// from `0.0` (unchanged) to `1.0` (fully gray)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// assert_eq!(rgb(255, 0, 0).grayscale_mix(0.5), rgb(170, 43, 43));
+    /// assert_eq!(rgb(255, 0, 0).grayscale_mix(1.0), Color::grayscale(85));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn grayscale_mix(self, amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        let average = (f32::from(self.r) + f32::from(self.g) + f32::from(self.b)) / 3.0;
+        let mix = |channel: u8| {
+            let channel = f32::from(channel);
+            (channel + (average - channel) * amount).round() as u8
+        };
+        Self::new(mix(self.r), mix(self.g), mix(self.b))
+    }
+
+    /// Rotates the color's hue in HSV by `degrees`, wrapping around at 360°
+    ///
+    /// Shifting by 180° gives the complement, and by 120° and 240° gives a triad
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// assert_eq!(rgb(255, 0, 0).shift_hue(120.0), rgb(0, 255, 0));
+    /// assert_eq!(rgb(255, 0, 0).shift_hue(-120.0), rgb(0, 0, 255));
+    /// assert_eq!(rgb(255, 128, 0).shift_hue(180.0), rgb(0, 127, 255));
+    /// ```
+    #[must_use]
+    pub fn shift_hue(self, degrees: f32) -> Self {
+        let (hue, saturation, value) = self.to_hsv();
+        Self::from_hsv(hue + degrees, saturation, value)
+    }
+
+    /// Mixes the color with `other`, where `amount` is from `0.0` (`self`) to `1.0` (`other`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// assert_eq!(Color::BLACK.blend(Color::WHITE, 0.5), Color::grayscale(128));
+    /// assert_eq!(rgb(255, 0, 0).blend(rgb(0, 0, 255), 0.25), rgb(191, 0, 64));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn blend(self, other: Self, amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        let mix = |from: u8, to: u8| {
+            let from = f32::from(from);
+            (from + (f32::from(to) - from) * amount).round() as u8
+        };
+        Self::new(mix(self.r, other.r), mix(self.g, other.g), mix(self.b, other.b))
+    }
+
+    /// Converts the color into its hue in degrees, and its saturation and value from `0.0` to `1.0`
+    fn to_hsv(self) -> (f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let [r, g, b] = [self.r, self.g, self.b].map(|channel| f32::from(channel) / 255.0);
+        let delta = f32::from(max - min) / 255.0;
+
+        let hue = if max == min {
+            0.0
+        } else if max == self.r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == self.g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        let value = f32::from(max) / 255.0;
+        let saturation = if max == 0 { 0.0 } else { delta / value };
+        (hue, saturation, value)
+    }
+
+    /// Creates a color from its hue in degrees, and its saturation and value from `0.0` to `1.0`
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        let hue = hue.rem_euclid(360.0) / 60.0;
+        let chroma = value * saturation;
+        let second = chroma * (1.0 - (hue.rem_euclid(2.0) - 1.0).abs());
+        let (r, g, b) = match hue as u8 {
+            0 => (chroma, second, 0.0),
+            1 => (second, chroma, 0.0),
+            2 => (0.0, chroma, second),
+            3 => (0.0, second, chroma),
+            4 => (second, 0.0, chroma),
+            _ => (chroma, 0.0, second),
+        };
+        let lightest = value - chroma;
+        let channel = |channel: f32| ((channel + lightest) * 255.0).round() as u8;
+        Self::new(channel(r), channel(g), channel(b))
+    }
+
     pub fn paint<T: Display>(item: T, foreground: Option<Self>, background: Option<Self>) -> impl Display {
         let mut style = yansi::Paint::new(item);
         if let Some(foreground) = foreground { style = style.fg(foreground.into()); }
@@ -36,6 +239,43 @@ impl Color {
     }
 }
 
+/// How highlighting changes one of the colors of a cell
+///
+/// Any [`Color`] or [`Option<Color>`] can be turned into an update that sets the color, where
+/// [`None`] clears it
+///
+/// # Example
+///
+/// ```
+/// # use canvas_tui::prelude::*;
+/// # fn main() -> Result<(), Error> {
+/// let mut canvas = Basic::filled_with(&(3, 1), ' ', Color::WHITE, Color::BLACK);
+/// canvas.highlight(&(1, 0), ColorUpdate::Keep, None)?;
+///
+/// assert_eq!(canvas.get(&(1, 0))?.foreground, Some(Color::WHITE));
+/// assert_eq!(canvas.get(&(1, 0))?.background, None);
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorUpdate {
+    /// Leaves the color already in the cell
+    Keep,
+    /// Replaces the color in the cell, clearing it if [`None`]
+    Set(Option<Color>),
+}
+
+impl From<Color> for ColorUpdate {
+    fn from(value: Color) -> Self {
+        Self::Set(Some(value))
+    }
+}
+
+impl From<Option<Color>> for ColorUpdate {
+    fn from(value: Option<Color>) -> Self {
+        Self::Set(value)
+    }
+}
+
 impl From<Color> for yansi::Color {
     fn from(value: Color) -> Self {
         Self::RGB(value.r, value.g, value.b)
@@ -2,7 +2,7 @@
 
 use std::fmt::Display;
 
-use crate::{num::{Vec2, Size}, canvas::Canvas, Error};
+use crate::{num::{Vec2, Size}, canvas::Canvas, shapes::{Grid, Rect}, Error};
 
 /// Represents the position of an object in relation to the canvas
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -37,6 +37,42 @@ pub enum Just {
     OffsetFrom(Box<Just>, Vec2),
     OffsetFromUnchecked(Box<Just>, Vec2),
     AtUnchecked(Vec2),
+
+    /// Next to a previously drawn rect, such as the shape from [`Canvas::draw`], see [`RelativeAnchor`]
+    RelativeTo(Rect, RelativeAnchor),
+}
+
+/// Which side of a rect an object is placed on with [`Just::RelativeTo`], and the gap between them
+///
+/// Objects placed to the side line up with the top of the rect, and objects placed above or below
+/// line up with the left of the rect
+///
+/// # Example
+///
+/// ```
+/// # use canvas_tui::prelude::*;
+/// use widgets::basic::title;
+/// # fn main() -> Result<(), Error> {
+/// let mut canvas = Basic::new(&(12, 5));
+/// let foo = canvas.draw(&Just::TopLeft, title("foo", None, None))?.shape;
+/// canvas.draw(&Just::RelativeTo(foo.clone(), RelativeAnchor::RightOf(1)), title("bar", None, None))?;
+/// canvas.draw(&Just::RelativeTo(foo, RelativeAnchor::Below(1)), title("baz", None, None))?;
+///
+/// // ............
+/// // . foo  bar .
+/// // ............
+/// // . baz ......
+/// // ............
+/// assert_eq!(canvas.get(&(8, 1))?.text, 'b');
+/// assert_eq!(canvas.get(&(2, 3))?.text, 'b');
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeAnchor {
+    RightOf(isize),
+    LeftOf(isize),
+    Below(isize),
+    Above(isize),
 }
 
 impl Just {
@@ -106,6 +142,18 @@ impl Just {
             Just::OffsetFrom(other, offset) => Self::compute_offset(other, *offset, canvas, object)?,
             Just::OffsetFromUnchecked(other, offset) => return Self::compute_offset(other, *offset, canvas, object),
             Just::AtUnchecked(pos) => return Ok(*pos),
+
+            // relative
+            Just::RelativeTo(rect, anchor) => {
+                let pos = match *anchor {
+                    RelativeAnchor::RightOf(gap) => rect.pos.add_x(rect.size.x + gap),
+                    RelativeAnchor::LeftOf(gap) => rect.pos.sub_x(object.x + gap),
+                    RelativeAnchor::Below(gap) => rect.pos.add_y(rect.size.y + gap),
+                    RelativeAnchor::Above(gap) => rect.pos.sub_y(object.y + gap),
+                };
+                if pos.x < 0 || pos.y < 0 { return self.oob_error(canvas, object); }
+                pos
+            },
         };
 
         let bottom_right = pos + object;
@@ -132,6 +180,36 @@ impl Just {
         canvas.window(self, size)
     }
 
+    /// Places an object at the top left of `cell` in `grid`
+    ///
+    /// The grid given by [`Canvas::grid`] includes the outlines around each cell, so use the grid
+    /// from [`inside`](crate::result::DrawResultMethods::inside) to place objects within the
+    /// outlines
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(9, 7));
+    /// let grid = canvas.grid(&Just::Centered, &(2, 1), &(2, 2), &box_chars::LIGHT).inside()?.shape;
+    /// canvas.text(&Just::grid_cell(Vec2::new(1, 1), &grid), "ab")?;
+    ///
+    /// // .........
+    /// // .┌──┬──┐.
+    /// // .│  │  │.
+    /// // .├──┼──┤.
+    /// // .│  │ab│.
+    /// // .└──┴──┘.
+    /// // .........
+    /// assert_eq!(canvas.get(&(5, 4))?.text, 'a');
+    /// # Ok(()) }
+    /// ```
+    #[must_use]
+    pub fn grid_cell(cell: Vec2, grid: &Grid) -> Self {
+        Self::At(grid.pos + cell * (grid.cell_size + grid.spacing) + grid.spacing)
+    }
+
     /// Offsets this current justification with `offset`
     #[must_use]
     pub fn offset(self, offset: impl Into<Vec2>) -> Self {
@@ -154,6 +232,20 @@ impl Display for Just {
     }
 }
 
+/// How text is aligned around the point given by a [justification](Just)
+///
+/// See [`Canvas::text_aligned`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextAlign {
+    /// The text starts at the point
+    #[default]
+    Left,
+    /// The text is centered on the point, preferring the left
+    Center,
+    /// The text ends at the point
+    Right,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,6 +299,74 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn center_right_offset() -> Result<(), Error> {
+        // ........
+        // ....ox/.
+        // ........
+        assert_eq!(Just::OffCenterRightBy(2).get(&(8, 3), &(2, 1))?, (4, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn center_left_offset() -> Result<(), Error> {
+        // ........
+        // ./ox....
+        // ........
+        assert_eq!(Just::OffCenterLeftBy(2).get(&(8, 3), &(2, 1))?, (2, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn center_left() -> Result<(), Error> {
+        // ......
+        // ......
+        // .ox...
+        // ......
+        // ......
+        assert_eq!(Just::CenterLeft.get(&(6, 5), &(2, 1))?, (1, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn center_right() -> Result<(), Error> {
+        // ......
+        // ......
+        // ...ox.
+        // ......
+        // ......
+        assert_eq!(Just::CenterRight.get(&(6, 5), &(2, 1))?, (3, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn center_sides_out_of_bounds() {
+        assert!(matches!(Just::CenterLeft.get(&(3, 3), &(4, 1)), Err(Error::JustificationOutOfBounds { .. })));
+        assert!(matches!(Just::CenterRight.get(&(3, 3), &(4, 1)), Err(Error::JustificationOutOfBounds { .. })));
+    }
+
+    #[test]
+    fn relative_to() -> Result<(), Error> {
+        // ........
+        // ..xxo...
+        // ..xx....
+        // ........
+        let rect = Rect { pos: Vec2::new(2, 1), size: Vec2::new(2, 2) };
+        assert_eq!(Just::RelativeTo(rect.clone(), RelativeAnchor::RightOf(0)).get(&(8, 4), &(1, 1))?, (4, 1));
+        assert_eq!(Just::RelativeTo(rect.clone(), RelativeAnchor::LeftOf(1)).get(&(8, 4), &(1, 1))?, (0, 1));
+        assert_eq!(Just::RelativeTo(rect, RelativeAnchor::Below(0)).get(&(8, 4), &(1, 1))?, (2, 3));
+        Ok(())
+    }
+
+    #[test]
+    fn relative_to_out_of_bounds() {
+        let rect = Rect { pos: Vec2::new(0, 0), size: Vec2::new(2, 2) };
+        assert!(matches!(
+            Just::RelativeTo(rect, RelativeAnchor::Above(0)).get(&(4, 4), &(1, 1)),
+            Err(Error::JustificationOutOfBounds { .. })
+        ));
+    }
+
     #[test]
     fn out_of_bounds() {
         assert!(matches!(Just::Centered.get(&(2, 2), &(5, 5)), Err(Error::JustificationOutOfBounds { .. })));
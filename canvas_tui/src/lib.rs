@@ -11,6 +11,9 @@ pub mod num;
 pub mod prelude;
 pub mod result;
 pub mod shapes;
+pub mod terminal;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod themes;
 pub mod widgets;
 
@@ -34,6 +37,12 @@ pub enum Error {
     TextOverflow { starting: Vec2, text: String, ending: Vec2, canvas: Vec2 },
     #[error("Object `{name}` didn't have enough space. It started at {pos} with dimensions {size}, but the canvas was only {canvas}")]
     ItemTooBig { pos: Vec2, size: Vec2, canvas: Vec2, name: &'static str },
+    #[error("couldn't get the size of the terminal, the output might not be a terminal")]
+    NoTerminalSize,
+    #[error("couldn't install a handler for the signal {0}")]
+    SignalHandler(&'static str),
+    #[error("couldn't enable raw mode: {0}")]
+    RawMode(String),
 }
 
 impl From<array2d::Error> for Error {
@@ -60,6 +60,82 @@ impl Vec2 {
     pub const fn sub_y(&self, off: isize) -> Self {
         Self { y: self.y - off, ..*self }
     }
+
+    /// The dot product of the two vectors
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// assert_eq!(Vec2::new(1, 2).dot(Vec2::new(3, 4)), 11);
+    /// assert_eq!(Vec2::new(1, 0).dot(Vec2::new(0, 1)), 0);
+    /// ```
+    #[must_use]
+    pub const fn dot(self, other: Self) -> isize {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The 2d cross product of the two vectors, positive if `other` is clockwise from `self`
+    /// (with y pointing down)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// assert_eq!(Vec2::new(1, 0).cross(Vec2::new(0, 1)), 1);
+    /// assert_eq!(Vec2::new(0, 1).cross(Vec2::new(1, 0)), -1);
+    /// assert_eq!(Vec2::new(2, 2).cross(Vec2::new(1, 1)), 0);
+    /// ```
+    #[must_use]
+    pub const fn cross(self, other: Self) -> isize {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Linearly interpolates from `self` to `other`, where `t` is from `0.0` (`self`) to `1.0`
+    /// (`other`), rounding to the nearest cell
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// let start = Vec2::new(0, 10);
+    /// let end = Vec2::new(10, 0);
+    /// assert_eq!(start.lerp(end, 0.25), (3, 8));
+    /// assert_eq!(start.lerp(end, 1.5), end);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |from: isize, to: isize| (from as f32 * (1.0 - t) + to as f32 * t).round() as isize;
+        Self::new(lerp(self.x, other.x), lerp(self.y, other.y))
+    }
+
+    /// Swaps `x` and `y`, turning a horizontal size into a vertical one and the other way around
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// assert_eq!(Vec2::new(10, 1).rotate_90(), (1, 10));
+    /// ```
+    #[must_use]
+    pub const fn rotate_90(self) -> Self {
+        Self { x: self.y, y: self.x }
+    }
+
+    /// Flips the vector to point the opposite way
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// assert_eq!(Vec2::new(3, -2).rotate_180(), (-3, 2));
+    /// ```
+    #[must_use]
+    pub const fn rotate_180(self) -> Self {
+        Self { x: -self.x, y: -self.y }
+    }
 }
 
 
@@ -104,6 +180,21 @@ pub trait Size {
                 name: "height"
             })
     }
+
+    /// Whether it's wider than it is tall, such as for laying widgets out in a row instead of a column
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// use canvas_tui::num::Size;
+    ///
+    /// assert!((80, 24).prefers_landscape());
+    /// assert!(!Basic::new(&(10, 10)).prefers_landscape());
+    /// ```
+    fn prefers_landscape(&self) -> bool {
+        self.width() > self.height()
+    }
 }
 
 
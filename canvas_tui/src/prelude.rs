@@ -3,7 +3,7 @@
 pub use crate::canvas::*;
 pub use crate::justification::*;
 pub use crate::Error;
-pub use crate::color::{Color, hex, rgb};
+pub use crate::color::{Color, ColorUpdate, hex, rgb};
 pub use crate::box_chars;
 pub use crate::result::*;
 pub use crate::num::Vec2;
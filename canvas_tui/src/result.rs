@@ -7,12 +7,15 @@
 //! [`log_result`](DrawResultMethods::log_result) can be helpful. 
 //! - Other methods are provided to modify the most recently drawn item such as
 //! [`colored`](DrawResultMethods::colored) or [`draw_inside`](DrawResultMethods::draw_inside)
+//! - [`GridResultMethods`] has extra methods for [grids](Canvas::grid) such as
+//! [`for_each_cell`](GridResultMethods::for_each_cell)
 
 use log::{error, Level};
 
 use crate::Error;
-use crate::color::Color;
+use crate::color::ColorUpdate;
 use crate::shapes::GrowFrom;
+use crate::widgets::WidgetSource;
 
 use super::canvas::{Canvas, Cell};
 use super::shapes::{DrawnShape, Grid, Rect, Single};
@@ -34,6 +37,32 @@ impl<'c, C: Canvas<Output = C>, S: DrawnShape> DrawInfo<'c, C, S> {
 
     pub fn canvas(&self) -> &C { self.output }
     pub fn canvas_mut(&mut self) -> &mut C { self.output }
+
+    /// Replaces the stored shape with `shape`, such as when the area to color is different from
+    /// what was drawn
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// use canvas_tui::shapes::Rect;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(5, 3));
+    /// canvas.text(&Just::Centered, "foo")
+    ///     .map(|info| info.with_shape(Rect { pos: Vec2::new(0, 1), size: Vec2::new(5, 1) }))
+    ///     .foreground(Color::WHITE)?;
+    ///
+    /// // .....
+    /// // -foo- (color represented by -)
+    /// // .....
+    /// assert_eq!(canvas.get(&(0, 1))?.foreground, Some(Color::WHITE));
+    /// assert_eq!(canvas.get(&(4, 1))?.foreground, Some(Color::WHITE));
+    /// # Ok(()) }
+    /// ```
+    #[must_use]
+    pub fn with_shape<S2: DrawnShape>(self, shape: S2) -> DrawInfo<'c, C, S2> {
+        DrawInfo { output: self.output, shape }
+    }
 }
 
 impl<'c, C: Canvas<Output = C>> DrawInfo<'c, C, Single> {
@@ -133,10 +162,10 @@ pub trait DrawResultMethods<'c, C: Canvas<Output = C>, S: DrawnShape>: Sized {
     /// ```
     fn colored(
         self,
-        foreground: impl Into<Option<Color>>,
-        background: impl Into<Option<Color>>
+        foreground: impl Into<ColorUpdate>,
+        background: impl Into<ColorUpdate>
     ) -> DrawResult<'c, C, S>;
-    /// Colors the last drawn object with `foreground`
+    /// Colors the last drawn object with `foreground`, keeping the background
     ///
     /// See [`Self::colored`]
     ///
@@ -144,10 +173,10 @@ pub trait DrawResultMethods<'c, C: Canvas<Output = C>, S: DrawnShape>: Sized {
     ///
     /// - If the result is an error
     /// - If there is not enough room for the color (when after [`Self::grow_profile`])
-    fn foreground(self, foreground: impl Into<Option<Color>>) -> DrawResult<'c, C, S> {
-        self.colored(foreground, None)
+    fn foreground(self, foreground: impl Into<ColorUpdate>) -> DrawResult<'c, C, S> {
+        self.colored(foreground, ColorUpdate::Keep)
     }
-    /// Colors the last drawn object with `background`
+    /// Colors the last drawn object with `background`, keeping the foreground
     ///
     /// See [`Self::colored`]
     ///
@@ -155,8 +184,8 @@ pub trait DrawResultMethods<'c, C: Canvas<Output = C>, S: DrawnShape>: Sized {
     ///
     /// - If the result is an error
     /// - If there is not enough room for the color (when after [`Self::grow_profile`])
-    fn background(self, background: impl Into<Option<Color>>) -> DrawResult<'c, C, S> {
-        self.colored(None, background)
+    fn background(self, background: impl Into<ColorUpdate>) -> DrawResult<'c, C, S> {
+        self.colored(ColorUpdate::Keep, background)
     }
     /// Fills the profile with `chr`
     ///
@@ -302,6 +331,90 @@ pub trait DrawResultMethods<'c, C: Canvas<Output = C>, S: DrawnShape>: Sized {
     /// # Ok(()) }
     /// ```
     fn draw_inside(self, drawer: <S::Grown as DrawnShape>::Drawer<C>) -> DrawResult<'c, C, <S::Grown as DrawnShape>::Grown>;
+    /// Draws `widget` with its top left corner `offset` away from the top left corner of the
+    /// last drawn item, see [`Canvas::draw_absolute`]
+    ///
+    /// **Note:** The profile returned is the newly drawn widget
+    ///
+    /// # Errors
+    ///
+    /// - If the result is already an error
+    /// - If the widget doesn't have enough space
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// use widgets::basic::title;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(12, 3));
+    /// let info = canvas
+    ///     .draw(&Just::CenterLeft, title("foo", None, None))
+    ///     .and_draw(Vec2::new(5, 0), title("bar", None, None))?;
+    ///
+    /// // ............
+    /// // . foo  bar .
+    /// // ............
+    /// assert_eq!(info.shape.pos, (6, 1));
+    /// assert_eq!(canvas.get(&(7, 1))?.text, 'b');
+    /// # Ok(()) }
+    /// ```
+    fn and_draw<W: WidgetSource>(self, offset: Vec2, widget: W) -> DrawResult<'c, C, Rect>;
+    /// Replaces the stored profile with its bounding [`Rect`], so results of different shapes can
+    /// be stored together
+    ///
+    /// # Errors
+    ///
+    /// - If the result is an error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(9, 7));
+    /// let info = canvas.grid(&Just::Centered, &(2, 1), &(2, 2), &box_chars::LIGHT).into_rect()?;
+    ///
+    /// // .........
+    /// // .┌──┬──┐.
+    /// // .│  │  │.
+    /// // .├──┼──┤.
+    /// // .│  │  │.
+    /// // .└──┴──┘.
+    /// // .........
+    /// assert_eq!(info.shape.pos, (1, 1));
+    /// assert_eq!(info.shape.size, (7, 5));
+    /// # Ok(()) }
+    /// ```
+    fn into_rect(self) -> DrawResult<'c, C, Rect>;
+    /// The top left corner of the smallest rect containing the drawn shape, see [`into_rect`](Self::into_rect)
+    ///
+    /// # Errors
+    ///
+    /// - If the result is an error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(7, 3));
+    /// let result = canvas.text(&Just::Centered, "foo");
+    ///
+    /// // .......
+    /// // ..foo..
+    /// // .......
+    /// assert_eq!(result.position()?, (2, 1));
+    /// assert_eq!(result.size()?, (3, 1));
+    /// # Ok(()) }
+    /// ```
+    fn position(&self) -> Result<Vec2, Error>;
+    /// The size of the smallest rect containing the drawn shape, see [`into_rect`](Self::into_rect)
+    ///
+    /// # Errors
+    ///
+    /// - If the result is an error
+    fn size(&self) -> Result<Vec2, Error>;
     /// Ignore the result, especially for when the canvas is using
     /// [`when_error`](Canvas::when_error)
     ///
@@ -334,8 +447,8 @@ pub trait DrawResultMethods<'c, C: Canvas<Output = C>, S: DrawnShape>: Sized {
 impl<'c, C: Canvas<Output = C>, S: DrawnShape> DrawResultMethods<'c, C, S> for DrawResult<'c, C, S> {
     fn colored(
         self,
-        foreground: impl Into<Option<Color>>,
-        background: impl Into<Option<Color>>
+        foreground: impl Into<ColorUpdate>,
+        background: impl Into<ColorUpdate>
     ) -> DrawResult<'c, C, S> {
         self.and_then(|DrawInfo { output, shape }|
             shape.color(output, foreground, background)
@@ -378,6 +491,26 @@ impl<'c, C: Canvas<Output = C>, S: DrawnShape> DrawResultMethods<'c, C, S> for D
         )
     }
 
+    fn and_draw<W: WidgetSource>(self, offset: Vec2, widget: W) -> DrawResult<'c, C, Rect> {
+        self.and_then(|DrawInfo { output, shape }|
+            output.draw_absolute(&(shape.bounds().pos + offset), widget)
+        )
+    }
+
+    fn into_rect(self) -> DrawResult<'c, C, Rect> {
+        self.map(|DrawInfo { output, shape }|
+            DrawInfo { output, shape: shape.bounds() }
+        )
+    }
+
+    fn position(&self) -> Result<Vec2, Error> {
+        self.as_ref().map(|info| info.shape.bounds().pos).map_err(Clone::clone)
+    }
+
+    fn size(&self) -> Result<Vec2, Error> {
+        self.as_ref().map(|info| info.shape.bounds().size).map_err(Clone::clone)
+    }
+
     fn discard_info(self) -> Result<(), Error> { self.map(|_| ()) }
 
     fn log_result(self) {
@@ -393,6 +526,107 @@ impl<'c, C: Canvas<Output = C>, S: DrawnShape> DrawResultMethods<'c, C, S> for D
     }
 }
 
+/// Extra methods that can be run on the [`DrawResult`] of a [grid](Canvas::grid)
+pub trait GridResultMethods<'c, C: Canvas<Output = C>>: Sized {
+    /// Runs `drawer` on the inside of each cell of the grid, giving it a window into the cell and
+    /// the cell's position in the grid
+    ///
+    /// This is the same as [`draw_inside`](DrawResultMethods::draw_inside), but the drawer doesn't
+    /// need to be boxed and can mutate what it captures
+    ///
+    /// **Note:** The profile returned is the same as before the method was called
+    ///
+    /// # Errors
+    ///
+    /// - If the result is already an error
+    /// - If the drawer returns an error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(9, 7));
+    ///
+    /// let mut count = 0;
+    /// canvas.grid(&Just::Centered, &(2, 1), &(2, 2), &box_chars::LIGHT)
+    ///     .for_each_cell(|mut canvas, _| {
+    ///         count += 1;
+    ///         canvas.text(&Just::Centered, &count.to_string())?;
+    ///         Ok(())
+    ///     })?;
+    ///
+    /// assert_eq!(count, 4);
+    /// # Ok(()) }
+    /// ```
+    fn for_each_cell<F: FnMut(C::Window<'_>, Vec2) -> Result<(), Error>>(self, drawer: F) -> DrawResult<'c, C, Grid>;
+    /// Colors the inside of the cell at column `col` and row `row` of the grid
+    ///
+    /// **Note:** The profile returned is the same as before the method was called
+    ///
+    /// # Errors
+    ///
+    /// - If the result is already an error
+    /// - If the cell isn't in the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(9, 7));
+    /// canvas.grid(&Just::Centered, &(2, 1), &(2, 2), &box_chars::LIGHT)
+    ///     .highlight_cell(1, 1, None, Color::WHITE)?;
+    ///
+    /// // .........
+    /// // .┌──┬──┐.
+    /// // .│  │  │.
+    /// // .├──┼──┤.
+    /// // .│  │--│. (highlight represented by -)
+    /// // .└──┴──┘.
+    /// // .........
+    /// assert_eq!(canvas.get(&(5, 4))?.background, Some(Color::WHITE));
+    /// assert_eq!(canvas.get(&(6, 4))?.background, Some(Color::WHITE));
+    /// assert_eq!(canvas.get(&(2, 2))?.background, None);
+    /// # Ok(()) }
+    /// ```
+    fn highlight_cell(
+        self,
+        col: isize,
+        row: isize,
+        foreground: impl Into<ColorUpdate>,
+        background: impl Into<ColorUpdate>
+    ) -> DrawResult<'c, C, Grid>;
+}
+
+impl<'c, C: Canvas<Output = C>> GridResultMethods<'c, C> for DrawResult<'c, C, Grid> {
+    fn for_each_cell<F: FnMut(C::Window<'_>, Vec2) -> Result<(), Error>>(self, mut drawer: F) -> DrawResult<'c, C, Grid> {
+        self.and_then(|DrawInfo { output, shape }| {
+            for (cell, rect) in shape.grow(&(-1, -1)).cells() {
+                drawer(output.window_absolute(&rect.pos, &rect.size)?, cell)?;
+            }
+            Ok(DrawInfo { output, shape })
+        })
+    }
+
+    fn highlight_cell(
+        self,
+        col: isize,
+        row: isize,
+        foreground: impl Into<ColorUpdate>,
+        background: impl Into<ColorUpdate>
+    ) -> DrawResult<'c, C, Grid> {
+        self.and_then(|DrawInfo { output, shape }| {
+            let cell = Vec2::new(col, row);
+            let (_, rect) = shape.grow(&(-1, -1)).cells()
+                .find(|&(other, _)| other == cell)
+                .ok_or(Error::OutOfBounds(col, row))?;
+            output.highlight_box(&rect.pos, &rect.size, foreground, background)?;
+            Ok(DrawInfo { output, shape })
+        })
+    }
+}
+
 impl<'c, C: Canvas<Output = C>, S: DrawnShape> Size for DrawResult<'c, C, S> {
     fn width(&self) -> isize { self.as_ref().expect("asked for the width of an errored canvas").canvas().width() }
     fn height(&self) -> isize { self.as_ref().expect("asked for the height of an errored canvas").canvas().height() }
@@ -412,8 +646,8 @@ impl<'c, C: Canvas<Output = C>, S: DrawnShape> Canvas for DrawResult<'c, C, S> {
     fn highlight_without_catch(
         &mut self,
         pos: Vec2,
-        foreground: Option<Color>,
-        background: Option<Color>
+        foreground: ColorUpdate,
+        background: ColorUpdate
     ) -> Result<&mut C, Error> {
         match self {
             Ok(info) => info.canvas_mut().highlight_without_catch(pos, foreground, background),
@@ -25,8 +25,8 @@ pub trait DrawnShape: Sized {
     fn color<C: Canvas<Output = C>>(
         self,
         canvas: &mut C,
-        foreground: impl Into<Option<Color>>,
-        background: impl Into<Option<Color>>
+        foreground: impl Into<ColorUpdate>,
+        background: impl Into<ColorUpdate>
     ) -> DrawResult<C, Self>;
     /// Fills a `canvas` with `chr` in this shape
     ///
@@ -47,6 +47,8 @@ pub trait DrawnShape: Sized {
     ///     - If a window cannot be made
     /// - If one of the drawers returns an error
     fn draw<C: Canvas<Output = C>>(self, canvas: &mut C, drawer: Self::Drawer<C>) -> DrawResult<C, Self>;
+    /// The smallest [`Rect`] containing the whole shape
+    fn bounds(&self) -> Rect;
 }
 
 /// Determines how a shape should be grown to expand to a certain width or height in
@@ -58,6 +60,31 @@ pub enum GrowFrom {
     TopRight,
     BottomLeft,
     BottomRight,
+    /// Grows each edge by exactly its inset, ignoring the goal size
+    ///
+    /// Negative insets are treated as 0, so the shape never shrinks
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(9, 3));
+    /// canvas.text(&Just::Centered, "foo")
+    ///     // the goal of 9 is ignored, the text only grows by 2 on the left and 1 on the right
+    ///     .expand_profile(9, None, GrowFrom::FromEdges { top: 0, right: 1, bottom: 0, left: 2 })
+    ///     .foreground(Color::WHITE)?;
+    ///
+    /// // .........
+    /// // .--foo-.. (color represented by -)
+    /// // .........
+    /// assert_eq!(canvas.get(&(0, 1))?.foreground, None);
+    /// assert_eq!(canvas.get(&(1, 1))?.foreground, Some(Color::WHITE));
+    /// assert_eq!(canvas.get(&(6, 1))?.foreground, Some(Color::WHITE));
+    /// assert_eq!(canvas.get(&(7, 1))?.foreground, None);
+    /// # Ok(()) }
+    /// ```
+    FromEdges { top: isize, right: isize, bottom: isize, left: isize },
 }
 
 impl GrowFrom {
@@ -74,6 +101,21 @@ impl GrowFrom {
             GrowFrom::TopRight => pos.sub_x(goal.x - current.x),
             GrowFrom::BottomLeft => pos.sub_y(goal.y - current.y),
             GrowFrom::BottomRight => pos - (goal - current),
+            GrowFrom::FromEdges { top, left, .. } => pos - Vec2::new(left.max(0), top.max(0)),
+        }
+    }
+
+    /// The size a shape of size `current` should grow to when it's expanded to `goal`
+    ///
+    /// This is `goal`, except for [`GrowFrom::FromEdges`] which always grows by its insets
+    #[must_use]
+    pub fn goal(&self, current: Vec2, goal: Vec2) -> Vec2 {
+        match *self {
+            Self::FromEdges { top, right, bottom, left } => current + Vec2::new(
+                left.max(0) + right.max(0),
+                top.max(0) + bottom.max(0),
+            ),
+            _ => goal,
         }
     }
 }
@@ -96,15 +138,15 @@ impl DrawnShape for Single {
     }
 
     fn expand_to(&self, x: Option<isize>, y: Option<isize>, from: GrowFrom) -> Self::Grown {
-        let size = Vec2::new(x.unwrap_or(1), y.unwrap_or(1));
+        let size = from.goal(Vec2::ONE, Vec2::new(x.unwrap_or(1), y.unwrap_or(1)));
         Rect { pos: from.grow(self.pos, Vec2::ONE, size), size }
     }
     
     fn color<C: Canvas<Output = C>>(
         self,
         canvas: &mut C,
-        foreground: impl Into<Option<Color>>,
-        background: impl Into<Option<Color>>
+        foreground: impl Into<ColorUpdate>,
+        background: impl Into<ColorUpdate>
     ) -> DrawResult<C, Self> {
         canvas.highlight(&self.pos, foreground, background)
     }
@@ -117,17 +159,106 @@ impl DrawnShape for Single {
         let window = canvas.window_absolute(&self.pos, &(1, 1));
         window.and_then(drawer).map(|_| DrawInfo::new(canvas, self))
     }
+
+    fn bounds(&self) -> Rect {
+        Rect { pos: self.pos, size: Vec2::ONE }
+    }
 }
 
 /// A rectangle
 ///
 /// The shape for most items drawn to the canvas including [`text`](Canvas::text), [`rect`](Canvas::rect), and [widgets](Canvas::draw)
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Rect {
     pub pos: Vec2,
     pub size: Vec2
 }
 
+impl Rect {
+    /// Whether `pos` is inside the rectangle
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// use canvas_tui::shapes::Rect;
+    ///
+    /// let rect = Rect { pos: Vec2::new(1, 1), size: Vec2::new(3, 2) };
+    /// assert!(rect.contains(Vec2::new(1, 1)));
+    /// assert!(rect.contains(Vec2::new(3, 2)));
+    /// assert!(!rect.contains(Vec2::new(4, 2)));
+    /// assert!(!rect.contains(Vec2::new(0, 1)));
+    /// ```
+    #[must_use]
+    pub const fn contains(&self, pos: Vec2) -> bool {
+        pos.x >= self.pos.x && pos.x < self.pos.x + self.size.x
+            && pos.y >= self.pos.y && pos.y < self.pos.y + self.size.y
+    }
+
+    /// Whether the two rectangles overlap by at least one cell
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// use canvas_tui::shapes::Rect;
+    ///
+    /// let rect = Rect { pos: Vec2::new(0, 0), size: Vec2::new(3, 3) };
+    /// assert!(rect.intersects(&Rect { pos: Vec2::new(2, 2), size: Vec2::new(3, 3) }));
+    /// assert!(!rect.intersects(&Rect { pos: Vec2::new(3, 0), size: Vec2::new(3, 3) }));
+    /// ```
+    #[must_use]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The area shared by both rectangles, or `None` if they don't overlap
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// use canvas_tui::shapes::Rect;
+    ///
+    /// let rect = Rect { pos: Vec2::new(0, 0), size: Vec2::new(3, 3) };
+    /// assert_eq!(
+    ///     rect.intersection(&Rect { pos: Vec2::new(2, 1), size: Vec2::new(3, 3) }),
+    ///     Some(Rect { pos: Vec2::new(2, 1), size: Vec2::new(1, 2) })
+    /// );
+    /// assert_eq!(rect.intersection(&Rect { pos: Vec2::new(0, 3), size: Vec2::new(3, 3) }), None);
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let start = Vec2::new(self.pos.x.max(other.pos.x), self.pos.y.max(other.pos.y));
+        let (end, other_end) = (self.pos + self.size, other.pos + other.size);
+        let end = Vec2::new(end.x.min(other_end.x), end.y.min(other_end.y));
+        (start.x < end.x && start.y < end.y)
+            .then(|| Self { pos: start, size: end - start })
+    }
+
+    /// The smallest rectangle containing both rectangles
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// use canvas_tui::shapes::Rect;
+    ///
+    /// let rect = Rect { pos: Vec2::new(1, 0), size: Vec2::new(2, 2) };
+    /// assert_eq!(
+    ///     rect.union(&Rect { pos: Vec2::new(4, 3), size: Vec2::new(1, 1) }),
+    ///     Rect { pos: Vec2::new(1, 0), size: Vec2::new(4, 4) }
+    /// );
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let start = Vec2::new(self.pos.x.min(other.pos.x), self.pos.y.min(other.pos.y));
+        let (end, other_end) = (self.pos + self.size, other.pos + other.size);
+        let end = Vec2::new(end.x.max(other_end.x), end.y.max(other_end.y));
+        Self { pos: start, size: end - start }
+    }
+}
+
 impl DrawnShape for Rect {
     type Grown = Self;
     type Drawer<C: Canvas<Output = C>> = Box<dyn FnOnce(C::Window<'_>) -> Result<(), Error>>;
@@ -139,15 +270,15 @@ impl DrawnShape for Rect {
 
     fn expand_to(&self, x: Option<isize>, y: Option<isize>, from: GrowFrom) -> Self::Grown {
         let current = self.size;
-        let goal = Vec2::new(x.unwrap_or(current.x), y.unwrap_or(current.y));
+        let goal = from.goal(current, Vec2::new(x.unwrap_or(current.x), y.unwrap_or(current.y)));
         Self { pos: from.grow(self.pos, current, goal), size: goal }
     }
     
     fn color<C: Canvas<Output = C>>(
         self,
         canvas: &mut C,
-        foreground: impl Into<Option<Color>>,
-        background: impl Into<Option<Color>>
+        foreground: impl Into<ColorUpdate>,
+        background: impl Into<ColorUpdate>
     ) -> DrawResult<C, Self> {
         canvas.highlight_box(&self.pos, &self.size, foreground, background)
     }
@@ -160,6 +291,10 @@ impl DrawnShape for Rect {
         let window = canvas.window_absolute(&self.pos, &self.size);
         window.and_then(drawer).map(|_| DrawInfo::new(canvas, self))
     }
+
+    fn bounds(&self) -> Rect {
+        Rect { pos: self.pos, size: self.size }
+    }
 }
 
 /// A grid including its dimensions, a spacing between each cell, and the size of each cell
@@ -181,6 +316,38 @@ impl Grid {
         dims * (cell_size + spacing) + spacing
     }
 
+    /// Iterates over each cell of the grid, giving its position in the grid and the area it
+    /// takes up on the canvas
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(9, 7));
+    /// let grid = canvas.grid(&Just::Centered, &(2, 1), &(2, 2), &box_chars::LIGHT).inside()?.shape;
+    /// for (cell, rect) in grid.cells() {
+    ///     canvas.text_absolute(&rect.pos, &format!("{}{}", cell.x, cell.y))?;
+    /// }
+    ///
+    /// // .........
+    /// // .┌──┬──┐.
+    /// // .│00│10│.
+    /// // .├──┼──┤.
+    /// // .│01│11│.
+    /// // .└──┴──┘.
+    /// // .........
+    /// assert_eq!(canvas.get(&(5, 2))?.text, '1');
+    /// assert_eq!(canvas.get(&(3, 4))?.text, '1');
+    /// # Ok(()) }
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = (Vec2, Rect)> {
+        let Self { pos, dims, cell_size, spacing } = *self;
+        dims.into_iter().map(move |cell| {
+            (cell, Rect { pos: pos + cell * (cell_size + spacing) + spacing, size: cell_size })
+        })
+    }
+
     #[must_use]
     pub fn cell_size_from_full_size(&self, goal: Vec2) -> Vec2 {
         let Self { dims, spacing, .. } = *self;
@@ -212,7 +379,7 @@ impl DrawnShape for Grid {
     // but there isn't one
     fn expand_to(&self, x: Option<isize>, y: Option<isize>, from: GrowFrom) -> Self::Grown {
         let current = self.full_size();
-        let goal = Vec2::new(x.unwrap_or(current.x), y.unwrap_or(current.y));
+        let goal = from.goal(current, Vec2::new(x.unwrap_or(current.x), y.unwrap_or(current.y)));
         // compute a goal cell size from the goal full size
         let cell_size = self.cell_size_from_full_size(goal);
         // recompute the goal because the new cell size might be less than what is needed (because integers)
@@ -241,8 +408,8 @@ impl DrawnShape for Grid {
     fn color<C: Canvas<Output = C>>(
         self,
         canvas: &mut C,
-        foreground: impl Into<Option<Color>>,
-        background: impl Into<Option<Color>>
+        foreground: impl Into<ColorUpdate>,
+        background: impl Into<ColorUpdate>
     ) -> DrawResult<C, Self> {
         let full_spacing = self.cell_size + self.spacing;
 
@@ -268,4 +435,170 @@ impl DrawnShape for Grid {
         }
         Ok(DrawInfo::new(canvas, self))
     }
+
+    // the cells start at pos + spacing, which can overlap the position for grids drawn with
+    // outlines (see Canvas::grid_absolute)
+    fn bounds(&self) -> Rect {
+        let Self { pos, dims, cell_size, spacing } = *self;
+        Rect { pos: pos + spacing, size: dims * (cell_size + spacing) - spacing }
+    }
+}
+
+/// A grid where each column and row can have a different size, including a spacing between each
+/// cell
+///
+/// Only used in [`Canvas::variable_grid_absolute`]
+#[derive(Debug, Clone)]
+pub struct VariableGrid {
+    pub pos: Vec2,
+    pub col_widths: Vec<isize>,
+    pub row_heights: Vec<isize>,
+    pub spacing: Vec2,
+}
+
+impl VariableGrid {
+    /// The amount of columns and rows in the grid
+    #[must_use]
+    pub fn dims(&self) -> Vec2 {
+        let cols = self.col_widths.len();
+        let rows = self.row_heights.len();
+        Vec2::new(
+            cols.try_into().expect("column count to fit in an isize"),
+            rows.try_into().expect("row count to fit in an isize"),
+        )
+    }
+
+    /// The full size of the grid from edge to edge
+    #[must_use]
+    pub fn full_size(&self) -> Vec2 {
+        let cells = Vec2::new(self.col_widths.iter().sum(), self.row_heights.iter().sum());
+        cells + self.dims() * self.spacing + self.spacing
+    }
+
+    /// Iterates over each cell of the grid, giving its position in the grid and the area it
+    /// takes up on the canvas
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(8, 6));
+    /// let grid = canvas.variable_grid_absolute(&(0, 0), &[1, 3], &[1, 2], &box_chars::LIGHT).inside()?.shape;
+    /// for (cell, rect) in grid.cells() {
+    ///     canvas.text_absolute(&rect.pos, &format!("{}", cell.x + cell.y))?;
+    /// }
+    ///
+    /// // ┌─┬───┐.
+    /// // │0│1  │.
+    /// // ├─┼───┤.
+    /// // │1│2  │.
+    /// // │ │   │.
+    /// // └─┴───┘.
+    /// assert_eq!(canvas.get(&(3, 1))?.text, '1');
+    /// assert_eq!(canvas.get(&(1, 3))?.text, '1');
+    /// assert_eq!(canvas.get(&(3, 3))?.text, '2');
+    /// # Ok(()) }
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = (Vec2, Rect)> + '_ {
+        let Self { pos, spacing, .. } = *self;
+        let cols = starts(&self.col_widths, spacing.x);
+        let rows = starts(&self.row_heights, spacing.y);
+        self.dims().into_iter().map(move |cell| {
+            let (x, y) = (cell.x.unsigned_abs(), cell.y.unsigned_abs());
+            let offset = Vec2::new(cols[x], rows[y]);
+            let size = Vec2::new(self.col_widths[x], self.row_heights[y]);
+            (cell, Rect { pos: pos + offset + spacing, size })
+        })
+    }
+}
+
+/// The offset of the start of each of `sizes` when placed in a row with `spacing` in between
+fn starts(sizes: &[isize], spacing: isize) -> Vec<isize> {
+    sizes.iter()
+        .scan(0, |start, size| {
+            let current = *start;
+            *start += size + spacing;
+            Some(current)
+        })
+        .collect()
+}
+
+/// Spreads `growth` evenly between `sizes`, giving any remainder to the first ones
+fn spread(sizes: &[isize], growth: isize) -> Vec<isize> {
+    let Ok(count) = isize::try_from(sizes.len()) else { return sizes.to_vec() };
+    if count == 0 { return Vec::new(); }
+    let (each, remainder) = (growth / count, growth % count);
+    (0..).zip(sizes)
+        .map(|(index, size)| size + each + if index < remainder.abs() { remainder.signum() } else { 0 })
+        .collect()
+}
+
+impl DrawnShape for VariableGrid {
+    type Grown = Self;
+    type Drawer<C: Canvas<Output = C>> = Box<dyn Fn(C::Window<'_>, Vec2) -> Result<(), Error>>;
+
+    fn grow(&self, size: &impl Size) -> Self::Grown {
+        let size = Vec2::from_size(size);
+        Self {
+            pos: self.pos + size,
+            col_widths: self.col_widths.iter().map(|width| width + size.x * 2).collect(),
+            row_heights: self.row_heights.iter().map(|height| height + size.y * 2).collect(),
+            spacing: self.spacing - size * 2,
+        }
+    }
+
+    // keeps the spacing and dims, spreading the growth between the columns and rows
+    fn expand_to(&self, x: Option<isize>, y: Option<isize>, from: GrowFrom) -> Self::Grown {
+        let current = self.full_size();
+        let goal = from.goal(current, Vec2::new(x.unwrap_or(current.x), y.unwrap_or(current.y)));
+        let growth = goal - current;
+        Self {
+            pos: from.grow(self.pos, current, goal),
+            col_widths: spread(&self.col_widths, growth.x),
+            row_heights: spread(&self.row_heights, growth.y),
+            spacing: self.spacing,
+        }
+    }
+
+    fn fill<C: Canvas<Output = C>>(self, canvas: &mut C, chr: char) -> DrawResult<C, Self> {
+        canvas.catch(canvas::check_bounds(self.pos, self.full_size(), canvas, "variable grid"))?;
+
+        for (_, rect) in self.cells() {
+            canvas.fill_box(&rect.pos, &rect.size, chr)?;
+        }
+
+        Ok(DrawInfo::new(canvas, self))
+    }
+
+    fn color<C: Canvas<Output = C>>(
+        self,
+        canvas: &mut C,
+        foreground: impl Into<ColorUpdate>,
+        background: impl Into<ColorUpdate>
+    ) -> DrawResult<C, Self> {
+        canvas.catch(canvas::check_bounds(self.pos, self.full_size(), canvas, "variable grid"))?;
+
+        let foreground = foreground.into();
+        let background = background.into();
+
+        for (_, rect) in self.cells() {
+            canvas.highlight_box(&rect.pos, &rect.size, foreground, background)?;
+        }
+
+        Ok(DrawInfo::new(canvas, self))
+    }
+
+    fn draw<C: Canvas<Output = C>>(self, canvas: &mut C, drawer: Self::Drawer<C>) -> DrawResult<C, Self> {
+        for (cell, rect) in self.cells() {
+            let window = canvas.window_absolute(&rect.pos, &rect.size);
+            window.and_then(|window| drawer(window, cell))?;
+        }
+        Ok(DrawInfo::new(canvas, self))
+    }
+
+    // the same as Grid::bounds, the cells start at pos + spacing
+    fn bounds(&self) -> Rect {
+        Rect { pos: self.pos + self.spacing, size: self.full_size() - self.spacing * 2 }
+    }
 }
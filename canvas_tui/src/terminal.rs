@@ -0,0 +1,208 @@
+//! Utilities for working with the terminal the canvas is printed to
+//!
+//! # Example
+//!
+//! ```no_run
+//! use canvas_tui::prelude::*;
+//!
+//! fn main() -> Result<(), Error> {
+//!     let mut canvas = Basic::new(&canvas_tui::terminal::terminal_size()?);
+//!     canvas.text(&Just::Centered, "hello")?;
+//!     canvas.print()
+//! }
+//! ```
+
+use crate::{num::Vec2, Error};
+
+/// Gets the size of the terminal in characters
+///
+/// # Errors
+///
+/// - If the size of the terminal couldn't be found, such as when the output isn't a terminal
+pub fn terminal_size() -> Result<Vec2, Error> {
+    let (width, height) = ::terminal_size::terminal_size().ok_or(Error::NoTerminalSize)?;
+    Vec2::try_from((usize::from(width.0), usize::from(height.0)))
+}
+
+/// The amount of times the terminal has been resized, incremented by [`handle_resize`]
+#[cfg(unix)]
+static RESIZES: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+/// How often the resize listeners check for a new resize
+#[cfg(unix)]
+const RESIZE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+
+/// The `SIGWINCH` handler that was installed before [`handle_resize`], such as crossterm's
+#[cfg(unix)]
+static PREVIOUS_HANDLER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(libc::SIG_DFL);
+
+/// Whether [`PREVIOUS_HANDLER`] takes the extra `siginfo` arguments
+#[cfg(unix)]
+static PREVIOUS_SIGINFO: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+#[cfg(unix)]
+extern "C" fn handle_resize(signal: libc::c_int, info: *mut libc::siginfo_t, context: *mut libc::c_void) {
+    use std::sync::atomic::Ordering;
+
+    // only async-signal-safe operations are allowed here,
+    // so the listeners do the actual work
+    RESIZES.fetch_add(1, Ordering::Relaxed);
+
+    // pass the signal on to whatever handled it before
+    let previous = PREVIOUS_HANDLER.load(Ordering::Relaxed);
+    if previous == libc::SIG_DFL || previous == libc::SIG_IGN { return; }
+    if PREVIOUS_SIGINFO.load(Ordering::Relaxed) {
+        // SAFETY: sigaction reported this handler as taking siginfo
+        let previous: extern "C" fn(libc::c_int, *mut libc::siginfo_t, *mut libc::c_void) = unsafe { std::mem::transmute(previous) };
+        previous(signal, info, context);
+    } else {
+        // SAFETY: sigaction reported this handler as a plain handler
+        let previous: extern "C" fn(libc::c_int) = unsafe { std::mem::transmute(previous) };
+        previous(signal);
+    }
+}
+
+/// Installs [`handle_resize`] for `SIGWINCH`, keeping the previous handler to chain to
+#[cfg(unix)]
+fn install_handler() -> Result<(), Error> {
+    use std::sync::atomic::Ordering;
+
+    let handler = handle_resize as extern "C" fn(libc::c_int, *mut libc::siginfo_t, *mut libc::c_void);
+    // SAFETY: sigaction is plain data, so all zeroes is valid
+    let mut action: libc::sigaction = unsafe { std::mem::zeroed() };
+    action.sa_sigaction = handler as libc::sighandler_t;
+    action.sa_flags = libc::SA_SIGINFO | libc::SA_RESTART;
+    // SAFETY: zeroed is a valid sigaction to be written over
+    let mut previous: libc::sigaction = unsafe { std::mem::zeroed() };
+    // SAFETY: the handler only touches atomics and chains to the previous handler, and both
+    // pointers are valid for the call
+    let res = unsafe {
+        libc::sigemptyset(&mut action.sa_mask);
+        libc::sigaction(libc::SIGWINCH, &action, &mut previous)
+    };
+    if res != 0 {
+        return Err(Error::SignalHandler("SIGWINCH"));
+    }
+
+    PREVIOUS_SIGINFO.store(previous.sa_flags & libc::SA_SIGINFO != 0, Ordering::Relaxed);
+    PREVIOUS_HANDLER.store(previous.sa_sigaction, Ordering::Relaxed);
+    Ok(())
+}
+
+/// A callback registered with [`on_resize`]
+///
+/// Each one has its own lock, so they can be called without holding onto [`LISTENERS`]
+#[cfg(unix)]
+type ResizeCallback = std::sync::Arc<std::sync::Mutex<dyn Fn(Vec2) + Send>>;
+
+/// The callbacks registered with [`on_resize`], and whether the listener thread is running
+#[cfg(unix)]
+struct ResizeListeners {
+    callbacks: Vec<(usize, ResizeCallback)>,
+    next_id: usize,
+    running: bool,
+}
+
+#[cfg(unix)]
+static LISTENERS: std::sync::Mutex<ResizeListeners> = std::sync::Mutex::new(ResizeListeners {
+    callbacks: Vec::new(),
+    next_id: 0,
+    running: false,
+});
+
+/// Whether the `SIGWINCH` handler was installed, only ever tried once
+#[cfg(unix)]
+static HANDLER: std::sync::OnceLock<Result<(), Error>> = std::sync::OnceLock::new();
+
+/// Locks `mutex`, ignoring any poisoning from a callback that panicked
+#[cfg(unix)]
+fn lock<T: ?Sized>(mutex: &std::sync::Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Checks for resizes and calls the callbacks, stopping once none are left
+#[cfg(unix)]
+fn listen_for_resizes() {
+    use std::sync::atomic::Ordering;
+
+    let mut seen = RESIZES.load(Ordering::Relaxed);
+    loop {
+        std::thread::sleep(RESIZE_POLL_INTERVAL);
+        let callbacks: Vec<ResizeCallback> = {
+            let mut listeners = lock(&LISTENERS);
+            if listeners.callbacks.is_empty() {
+                listeners.running = false;
+                return;
+            }
+            listeners.callbacks.iter().map(|(_, callback)| std::sync::Arc::clone(callback)).collect()
+        };
+
+        let resizes = RESIZES.load(Ordering::Relaxed);
+        if resizes != seen {
+            seen = resizes;
+            if let Ok(size) = terminal_size() {
+                // the listeners aren't locked anymore, so the callbacks can add or remove them
+                for callback in callbacks {
+                    (lock(&callback))(size);
+                }
+            }
+        }
+    }
+}
+
+/// Calls `callback` with the new size of the terminal whenever it is resized
+///
+/// The first call installs a handler for `SIGWINCH`, which only records that a resize happened
+/// before passing the signal on to any handler that was already installed. The callbacks are
+/// then called from a single listener thread, so they are free to do any work, including
+/// registering or dropping listeners. The callback is removed once the returned
+/// [`ResizeListener`] is dropped, and the thread stops once there are none left.
+///
+/// # Errors
+///
+/// - If the signal handler couldn't be installed
+///
+/// # Example
+///
+/// ```no_run
+/// use canvas_tui::{prelude::*, terminal};
+///
+/// fn main() -> Result<(), Error> {
+///     let _listener = terminal::on_resize(|size| {
+///         let mut canvas = Basic::new(&size);
+///         canvas.text(&Just::Centered, "resized!").discard_result();
+///         canvas.print().ok();
+///     })?;
+///     # Ok(())
+/// }
+/// ```
+#[cfg(unix)]
+pub fn on_resize<F: Fn(Vec2) + Send + 'static>(callback: F) -> Result<ResizeListener, Error> {
+    HANDLER.get_or_init(install_handler).clone()?;
+
+    let mut listeners = lock(&LISTENERS);
+    let id = listeners.next_id;
+    listeners.next_id += 1;
+    listeners.callbacks.push((id, std::sync::Arc::new(std::sync::Mutex::new(callback))));
+    if !listeners.running {
+        listeners.running = true;
+        std::thread::spawn(listen_for_resizes);
+    }
+
+    Ok(ResizeListener { id })
+}
+
+/// A callback registered with [`on_resize`], which is removed once this is dropped
+#[cfg(unix)]
+#[must_use = "the callback is removed as soon as the listener is dropped"]
+#[derive(Debug)]
+pub struct ResizeListener {
+    id: usize,
+}
+
+#[cfg(unix)]
+impl Drop for ResizeListener {
+    fn drop(&mut self) {
+        lock(&LISTENERS).callbacks.retain(|&(id, _)| id != self.id);
+    }
+}
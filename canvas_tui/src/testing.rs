@@ -0,0 +1,202 @@
+//! Helpers for testing what was drawn onto a canvas
+//!
+//! Only available with the `testing` feature
+
+use std::{fs, path::PathBuf};
+
+use crate::{prelude::*, num::Pos, canvas::WIDE_CHAR_PLACEHOLDER};
+
+/// The environment variable that, when set, makes [`assert_snapshot`] overwrite the baselines
+pub const UPDATE_SNAPSHOTS: &str = "UPDATE_SNAPSHOTS";
+
+/// Renders the text of `canvas` without any color, with each row on its own line
+///
+/// # Panics
+///
+/// - If the canvas has an outstanding error
+#[must_use]
+pub fn render(canvas: &impl Canvas) -> String {
+    canvas.error().expect("the canvas to not have an outstanding error");
+    (0..canvas.height())
+        .map(|y| (0..canvas.width())
+            .map(|x| canvas.get(&(x, y)).expect("in-bounds get to not fail").text)
+            // the wide character before it already covers this cell
+            .filter(|&chr| chr != WIDE_CHAR_PLACEHOLDER)
+            .collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Asserts that the text of `canvas` matches the baseline in `snapshots/{name}.snap`
+///
+/// The `snapshots` directory is placed in the directory of the crate being tested. If the
+/// baseline doesn't exist yet, or the [`UPDATE_SNAPSHOTS`] environment variable is set, the
+/// baseline is written instead. Only the text is compared, see [`render`]
+///
+/// # Panics
+///
+/// - If the canvas doesn't match the baseline, showing the rows that differ
+/// - If the baseline couldn't be read or written
+///
+/// # Example
+///
+/// ```no_run
+/// use canvas_tui::{prelude::*, testing::assert_snapshot};
+///
+/// # fn main() -> Result<(), Error> {
+/// let mut canvas = Basic::new(&(7, 3));
+/// canvas.draw(&Just::Centered, widgets::basic::title("foo", None, None))?;
+/// assert_snapshot(&canvas, "title");
+/// # Ok(()) }
+/// ```
+pub fn assert_snapshot(canvas: &impl Canvas, name: &str) {
+    let path = snapshot_path(name);
+    let rendered = render(canvas);
+
+    let baseline = match fs::read_to_string(&path) {
+        Ok(baseline) if std::env::var_os(UPDATE_SNAPSHOTS).is_none() => baseline,
+        _ => {
+            let dir = path.parent().expect("snapshots to be in a directory");
+            fs::create_dir_all(dir).expect("the snapshot directory to be creatable");
+            fs::write(&path, rendered + "\n").expect("the snapshot to be writable");
+            return;
+        },
+    };
+    let baseline = baseline.strip_suffix('\n').unwrap_or(&baseline);
+
+    if baseline != rendered {
+        panic!("snapshot `{name}` ({}) didn't match:\n{}", path.display(), diff(baseline, &rendered));
+    }
+}
+
+/// Assertions about single cells of a canvas, implemented for every [`Canvas`]
+///
+/// Each assertion panics with the position and what was found instead if it doesn't match
+///
+/// # Example
+///
+/// ```
+/// use canvas_tui::{prelude::*, testing::CanvasAssertions};
+///
+/// # fn main() -> Result<(), Error> {
+/// let mut canvas = Basic::new(&(7, 3));
+/// canvas.draw(&Just::Centered, widgets::basic::title("foo", Color::WHITE, None))?;
+///
+/// canvas.assert_text_at(&(2, 1), 'f');
+/// canvas.assert_fg_at(&(2, 1), Color::WHITE);
+/// canvas.assert_bg_at(&(2, 1), None);
+/// # Ok(()) }
+/// ```
+pub trait CanvasAssertions: Canvas {
+    /// Asserts that the text at `pos` is `expected`
+    ///
+    /// # Panics
+    ///
+    /// - If the text doesn't match or `pos` is out of bounds
+    #[track_caller]
+    fn assert_text_at(&self, pos: &impl Pos, expected: char) {
+        let actual = cell_at(self, pos).text;
+        assert!(actual == expected, "expected text {expected:?} at {}, found {actual:?}", Vec2::from_pos(pos));
+    }
+
+    /// Asserts that the foreground at `pos` is `expected`
+    ///
+    /// # Panics
+    ///
+    /// - If the foreground doesn't match or `pos` is out of bounds
+    #[track_caller]
+    fn assert_fg_at(&self, pos: &impl Pos, expected: impl Into<Option<Color>>) {
+        let expected = expected.into();
+        let actual = cell_at(self, pos).foreground;
+        assert!(actual == expected, "expected foreground {expected:?} at {}, found {actual:?}", Vec2::from_pos(pos));
+    }
+
+    /// Asserts that the background at `pos` is `expected`
+    ///
+    /// # Panics
+    ///
+    /// - If the background doesn't match or `pos` is out of bounds
+    #[track_caller]
+    fn assert_bg_at(&self, pos: &impl Pos, expected: impl Into<Option<Color>>) {
+        let expected = expected.into();
+        let actual = cell_at(self, pos).background;
+        assert!(actual == expected, "expected background {expected:?} at {}, found {actual:?}", Vec2::from_pos(pos));
+    }
+}
+
+impl<C: Canvas> CanvasAssertions for C {}
+
+/// Gets the cell at `pos`, panicking with the position if it can't
+#[track_caller]
+fn cell_at<C: Canvas>(canvas: &C, pos: &impl Pos) -> Cell {
+    canvas.get(pos).unwrap_or_else(|err| panic!("couldn't get the cell at {}: {err}", Vec2::from_pos(pos)))
+}
+
+/// The path of the baseline for the snapshot `name`
+fn snapshot_path(name: &str) -> PathBuf {
+    let root = std::env::var_os("CARGO_MANIFEST_DIR").unwrap_or_else(|| ".".into());
+    PathBuf::from(root).join("snapshots").join(format!("{name}.snap"))
+}
+
+/// Lists the rows that differ between `expected` and `actual`
+fn diff(expected: &str, actual: &str) -> String {
+    let mut expected = expected.lines();
+    let mut actual = actual.lines();
+    let mut diff = String::new();
+    for row in 0.. {
+        match (expected.next(), actual.next()) {
+            (None, None) => break,
+            (expected, actual) if expected == actual => {},
+            (expected, actual) => {
+                diff += &format!("row {row}:\n");
+                if let Some(expected) = expected { diff += &format!("- |{expected}|\n"); }
+                if let Some(actual) = actual { diff += &format!("+ |{actual}|\n"); }
+            },
+        }
+    }
+    diff
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn render_rows() -> Result<(), Error> {
+        let mut canvas = Basic::filled_with_text(&(3, 2), '.');
+        canvas.text_absolute(&(0, 1), "abc")?;
+        assert_eq!(render(&canvas), "...\nabc");
+        Ok(())
+    }
+
+    #[test]
+    fn render_wide_chars() -> Result<(), Error> {
+        let mut canvas = Basic::filled_with_text(&(3, 1), '.');
+        canvas.text_absolute(&(0, 0), "本")?;
+        assert_eq!(render(&canvas), "本.");
+        Ok(())
+    }
+
+    #[test]
+    fn cell_assertions() -> Result<(), Error> {
+        let mut canvas = Basic::filled_with_text(&(3, 1), '.');
+        canvas.text_absolute(&(1, 0), "a").colored(Color::WHITE, None)?;
+        canvas.assert_text_at(&(1, 0), 'a');
+        canvas.assert_fg_at(&(1, 0), Color::WHITE);
+        canvas.assert_bg_at(&(1, 0), None);
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "expected text 'b' at (1, 0), found 'a'")]
+    fn cell_assertion_message() {
+        let mut canvas = Basic::filled_with_text(&(3, 1), '.');
+        canvas.set(&(1, 0), 'a').expect("in-bounds set to not fail");
+        canvas.assert_text_at(&(1, 0), 'b');
+    }
+
+    #[test]
+    fn diff_rows() {
+        assert_eq!(diff("abc\ndef", "abc\ndeg\nhij"), "row 1:\n- |def|\n+ |deg|\nrow 2:\n+ |hij|\n");
+    }
+}
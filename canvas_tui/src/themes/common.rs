@@ -7,7 +7,12 @@
 //!     - [`Frappe`](catppuccin::Frappe)
 //!     - [`Macchiato`](catppuccin::Macchiato)
 //!     - [`Mocha`](catppuccin::Mocha)
+//! - [`Dracula`]
+//! - [`Monokai`]
 //! - [`OneDark`]
+//! - [`Solarized`](solarized)
+//!     - [`SolarizedLight`](solarized::SolarizedLight)
+//!     - [`SolarizedDark`](solarized::SolarizedDark)
 
 use crate::prelude::*;
 
@@ -28,6 +33,7 @@ macro_rules! highlights {
 /// The One Dark color theme from Atom
 ///
 /// Colors obtained from [here](https://github.com/joshdick/onedark.vim/blob/main/colors/onedark.vim)
+#[derive(Clone, Copy)]
 pub struct OneDark;
 impl OneDark {
     colors! {
@@ -76,6 +82,107 @@ impl BasicTheme for OneDark {
     }
 }
 
+/// The Dracula color theme
+///
+/// Colors obtained from [here](https://spec.draculatheme.com/). The darker backgrounds are from
+/// the spec's other backgrounds, and the lighter ones are shaded from the current line
+#[derive(Clone, Copy)]
+pub struct Dracula;
+impl Dracula {
+    colors! {
+        background: (40, 42, 54),
+        background_dark: (33, 34, 44),
+        background_darker: (25, 26, 33),
+        background_light: (52, 55, 70),
+        current_line: (68, 71, 90),
+        surface2: (82, 86, 108),
+        foreground: (248, 248, 242),
+        subtext: (205, 208, 218),
+        comment: (98, 114, 164),
+        cyan: (139, 233, 253),
+        green: (80, 250, 123),
+        orange: (255, 184, 108),
+        pink: (255, 121, 198),
+        purple: (189, 147, 249),
+        red: (255, 85, 85),
+        yellow: (241, 250, 140),
+    }
+    highlights![purple, pink, red, orange, yellow, green, cyan];
+}
+
+impl BasicTheme for Dracula {
+    fn base() -> Color { Self::background() }
+    fn mantle() -> Color { Self::background_dark() }
+    fn crust() -> Color { Self::background_darker() }
+
+    fn surface() -> Color { Self::background_light() }
+    fn surface1() -> Color { Self::current_line() }
+    fn surface2() -> Color { Self::surface2() }
+
+    fn text() -> Color { Self::foreground() }
+    fn subtext() -> Color { Self::subtext() }
+    fn special_text() -> Color { Color::WHITE }
+
+    fn success() -> Color { Self::green() }
+    fn warning() -> Color { Self::yellow() }
+    fn error() -> Color { Self::red() }
+    fn link() -> Color { Self::cyan() }
+
+    fn highlights() -> &'static [Color] {
+        Self::HIGHLIGHTS
+    }
+}
+
+/// The Monokai color theme
+///
+/// Colors obtained from [here](https://monokai.pro/), using the original palette. The
+/// backgrounds other than the line highlight and selection are shaded from the background
+#[derive(Clone, Copy)]
+pub struct Monokai;
+impl Monokai {
+    colors! {
+        background: (39, 40, 34),
+        mantle: (30, 31, 26),
+        crust: (23, 24, 20),
+        line_highlight: (62, 61, 50),
+        selection: (73, 72, 62),
+        surface2: (90, 89, 76),
+        foreground: (248, 248, 242),
+        subtext: (207, 207, 194),
+        comment: (117, 113, 94),
+        pink: (249, 38, 114),
+        orange: (253, 151, 31),
+        yellow: (230, 219, 116),
+        green: (166, 226, 46),
+        blue: (102, 217, 239),
+        purple: (174, 129, 255),
+    }
+    highlights![pink, orange, yellow, green, blue, purple];
+}
+
+impl BasicTheme for Monokai {
+    fn base() -> Color { Self::background() }
+    fn mantle() -> Color { Self::mantle() }
+    fn crust() -> Color { Self::crust() }
+
+    fn surface() -> Color { Self::line_highlight() }
+    fn surface1() -> Color { Self::selection() }
+    fn surface2() -> Color { Self::surface2() }
+
+    fn text() -> Color { Self::foreground() }
+    fn subtext() -> Color { Self::subtext() }
+    fn special_text() -> Color { Color::WHITE }
+
+    fn success() -> Color { Self::green() }
+    fn warning() -> Color { Self::yellow() }
+    fn error() -> Color { Self::pink() }
+    fn link() -> Color { Self::blue() }
+
+    fn highlights() -> &'static [Color] {
+        Self::HIGHLIGHTS
+    }
+}
+
 
 /// The Catppuccin color theme
 ///
@@ -113,6 +220,7 @@ pub mod catppuccin {
         };
     }
 
+    #[derive(Clone, Copy)]
     pub struct Latte;
     catppuccin!(Latte);
     impl Latte {
@@ -146,6 +254,7 @@ pub mod catppuccin {
         }
     }
 
+    #[derive(Clone, Copy)]
     pub struct Frappe;
     catppuccin!(Frappe);
     impl Frappe {
@@ -179,6 +288,7 @@ pub mod catppuccin {
         }
     }
 
+    #[derive(Clone, Copy)]
     pub struct Macchiato;
     catppuccin!(Macchiato);
     impl Macchiato {
@@ -212,6 +322,7 @@ pub mod catppuccin {
         }
     }
 
+    #[derive(Clone, Copy)]
     pub struct Mocha;
     catppuccin!(Mocha);
     impl Mocha {
@@ -245,3 +356,107 @@ pub mod catppuccin {
         }
     }
 }
+
+/// The Solarized color themes, with a light and dark variant
+///
+/// Colors obtained from [here](https://ethanschoonover.com/solarized/). Solarized only has two
+/// background tones for each variant, so the rest of the backgrounds are shaded from those
+pub mod solarized {
+    use crate::{prelude::*, themes::BasicTheme};
+
+    macro_rules! solarized {
+        ($name:ident) => {
+            impl $name {
+                colors! {
+                    base03: (0, 43, 54),
+                    base02: (7, 54, 66),
+                    base01: (88, 110, 117),
+                    base00: (101, 123, 131),
+                    base0: (131, 148, 150),
+                    base1: (147, 161, 161),
+                    base2: (238, 232, 213),
+                    base3: (253, 246, 227),
+                    yellow: (181, 137, 0),
+                    orange: (203, 75, 22),
+                    red: (220, 50, 47),
+                    magenta: (211, 54, 130),
+                    violet: (108, 113, 196),
+                    blue: (38, 139, 210),
+                    cyan: (42, 161, 152),
+                    green: (133, 153, 0),
+                }
+                highlights![yellow, orange, red, magenta, violet, blue, cyan, green];
+            }
+        };
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct SolarizedDark;
+    solarized!(SolarizedDark);
+    impl SolarizedDark {
+        colors! {
+            mantle: (0, 36, 46),
+            crust: (0, 30, 38),
+            surface1: (20, 68, 80),
+            surface2: (34, 82, 94),
+        }
+    }
+
+    impl BasicTheme for SolarizedDark {
+        fn base() -> Color { Self::base03() }
+        fn mantle() -> Color { Self::mantle() }
+        fn crust() -> Color { Self::crust() }
+
+        fn surface() -> Color { Self::base02() }
+        fn surface1() -> Color { Self::surface1() }
+        fn surface2() -> Color { Self::surface2() }
+
+        fn text() -> Color { Self::base0() }
+        fn subtext() -> Color { Self::base01() }
+        fn special_text() -> Color { Self::base1() }
+
+        fn success() -> Color { Self::green() }
+        fn warning() -> Color { Self::yellow() }
+        fn error() -> Color { Self::red() }
+        fn link() -> Color { Self::blue() }
+
+        fn highlights() -> &'static [Color] {
+            Self::HIGHLIGHTS
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct SolarizedLight;
+    solarized!(SolarizedLight);
+    impl SolarizedLight {
+        colors! {
+            crust: (228, 221, 201),
+            surface: (217, 210, 190),
+            surface1: (204, 197, 177),
+            surface2: (191, 184, 164),
+        }
+    }
+
+    impl BasicTheme for SolarizedLight {
+        fn base() -> Color { Self::base3() }
+        fn mantle() -> Color { Self::base2() }
+        fn crust() -> Color { Self::crust() }
+
+        fn surface() -> Color { Self::surface() }
+        fn surface1() -> Color { Self::surface1() }
+        fn surface2() -> Color { Self::surface2() }
+
+        fn text() -> Color { Self::base00() }
+        fn subtext() -> Color { Self::base1() }
+        fn special_text() -> Color { Self::base01() }
+
+        fn success() -> Color { Self::green() }
+        fn warning() -> Color { Self::yellow() }
+        fn error() -> Color { Self::red() }
+        fn link() -> Color { Self::blue() }
+
+        fn highlights() -> &'static [Color] {
+            Self::HIGHLIGHTS
+        }
+    }
+}
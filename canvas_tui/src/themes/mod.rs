@@ -1,6 +1,6 @@
 //! Default themes for [widgets]
 
-use crate::{prelude::*, widgets::{Theme, SelectableTheme}};
+use crate::{prelude::*, widgets::{Theme, SelectableTheme, Themed, Selectable}};
 
 pub mod common;
 pub use common::*;
@@ -52,11 +52,48 @@ pub trait BasicTheme {
     #[must_use] fn link() -> Color;
 
     #[must_use] fn highlights() -> &'static [Color];
+    /// The colors used for each series of data in a chart, in order of priority
+    ///
+    /// The nth series should use `chart_colors()[n % chart_colors().len()]`
+    #[must_use] fn chart_colors() -> &'static [Color] { Self::highlights() }
 
     #[must_use] 
     fn highlighted(self, highlight: Color) -> WithHighlight<Self> where Self: Sized {
         WithHighlight { theme: self, highlight }
     }
+
+    /// Wraps the theme in [`Themed`] to create widgets with it, shorthand for `Themed::new(theme)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::{OneDark, BasicTheme};
+    ///
+    /// let widgets = OneDark.into_themed();
+    /// let title = widgets.title("foo");
+    /// ```
+    #[must_use] 
+    fn into_themed(self) -> Themed<Self> where Self: Sized {
+        Themed::new(self)
+    }
+
+    /// Wraps the theme in [`Selectable`] with `selection` and `activated`, shorthand for
+    /// `Selectable::new(theme, selection, activated)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::{OneDark, BasicTheme};
+    ///
+    /// let widgets = OneDark.into_selectable(2, false);
+    /// let button = widgets.button(&2, "foo");
+    /// ```
+    #[must_use] 
+    fn into_selectable<V: PartialEq>(self, selection: V, activated: bool) -> Selectable<V, Self> where Self: Sized {
+        Selectable::new(self, selection, activated)
+    }
 }
 
 impl<T: BasicTheme> Theme for T {
@@ -78,6 +115,11 @@ impl<T: BasicTheme> Theme for T {
 
     fn rolling_selection_fg(&self) -> Color { Self::button_fg() }
     fn rolling_selection_bg(&self) -> Color { Self::button_bg() }
+
+    fn input_field_fg(&self) -> Color { Self::text() }
+    fn input_field_bg(&self) -> Color { Self::text_bg() }
+    fn input_field_cursor_fg(&self) -> Color { Self::text_bg() }
+    fn input_field_cursor_bg(&self) -> Color { Self::special_text() }
 }
 
 impl<T: BasicTheme> SelectableTheme for T {
@@ -100,6 +142,19 @@ impl<T: BasicTheme> SelectableTheme for T {
     fn rolling_selection_bg_activated(&self) -> Color { self.button_bg_hover() }
 }
 
+/// A theme with its titles and selections colored by a highlight, see [`BasicTheme::highlighted`]
+///
+/// # Example
+///
+/// ```
+/// use canvas_tui::prelude::*;
+/// use themes::{catppuccin::Frappe, BasicTheme};
+///
+/// let theme = Frappe.highlighted(Frappe::green());
+/// let months = widgets::Selectable::num(theme.clone(), 0, false);
+/// let days = widgets::Selectable::num(theme, 0, false);
+/// ```
+#[derive(Clone)]
 pub struct WithHighlight<T: Theme + SelectableTheme> {
     theme: T,
     highlight: Color,
@@ -124,6 +179,11 @@ impl<T: Theme + SelectableTheme> Theme for WithHighlight<T> {
 
     fn rolling_selection_fg(&self) -> Color { self.highlight_fg() }
     fn rolling_selection_bg(&self) -> Color { self.highlight }
+
+    fn input_field_fg(&self) -> Color { self.theme.input_field_fg() }
+    fn input_field_bg(&self) -> Color { self.theme.input_field_bg() }
+    fn input_field_cursor_fg(&self) -> Color { self.theme.input_field_cursor_fg() }
+    fn input_field_cursor_bg(&self) -> Color { self.highlight }
 }
 
 impl<T: Theme + SelectableTheme> SelectableTheme for WithHighlight<T> {
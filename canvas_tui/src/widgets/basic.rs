@@ -30,13 +30,17 @@
 
 use crate::prelude::*;
 
-use super::{truncate, length_of};
+use super::{truncate, length_of, check_min_width};
+use crate::canvas::{char_width, display_width, take_width};
 
 widget! {
     /// A generic thing of highlighted text
     ///
     /// See [`title`], [`button`], and [`toggle`] for more specific implementations
     ///
+    /// Without a `width`, the widget is as wide as the text is displayed plus a space on each
+    /// side. `text_width` replaces how wide the text is measured as
+    ///
     /// # Style
     ///
     /// ```text
@@ -44,6 +48,23 @@ widget! {
     /// ·-foo-· (highlight represented by -)
     /// ·······
     /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(8, 3));
+    /// let info = canvas.draw(&Just::Centered, basic::highlighted_text("日本", None, None))?;
+    ///
+    /// // ········
+    /// // ·-日本-· (highlight represented by -)
+    /// // ········
+    /// assert_eq!(info.shape.size, (6, 1));
+    /// Ok(())
+    /// # }
+    /// ```
     name: highlighted_text,
     args: (
         text: String [impl ToString as to_string],
@@ -53,9 +74,15 @@ widget! {
     optionals: (
         width: Option<usize>,
         truncate_from_end: Option<bool>,
+        text_width: Option<usize>,
     ),
     size: |&self, _| {
-        Ok(Vec2::new(super::width_or_length(self.width, &self.text, 2)?, 1))
+        let width: isize = match (self.width, self.text_width) {
+            (Some(width), _) => width.try_into().map_err(|_| Error::TooLarge("text length", width))?,
+            (None, Some(text_width)) => isize::try_from(text_width).map_err(|_| Error::TooLarge("text width", text_width))? + 2,
+            (None, None) => display_width(&self.text)? + 2,
+        };
+        Ok(Vec2::new(width, 1))
     },
     draw: |self, canvas| {
         canvas
@@ -111,6 +138,125 @@ widget! {
     )
 }
 
+widget! {
+    /// Highlighted text without any padding, as wide as the text is displayed
+    ///
+    /// For text with a space on each side, see [`highlighted_text`]
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ·····
+    /// ·---· (highlight represented by -)
+    /// ·····
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(5, 3));
+    /// let info = canvas.draw(&Just::Centered, basic::centered_label("foo", None, Color::WHITE))?;
+    /// assert_eq!(info.shape.size, (3, 1));
+    ///
+    /// // ·····
+    /// // ·foo·
+    /// // ·····
+    /// assert_eq!(canvas.get(&(1, 1))?.text, 'f');
+    /// assert_eq!(canvas.get(&(1, 1))?.background, Some(Color::WHITE));
+    /// assert_eq!(canvas.get(&(0, 1))?.background, None);
+    /// Ok(())
+    /// # }
+    /// ```
+    name: centered_label,
+    args: (
+        text: String [impl ToString as to_string],
+        foreground: Option<Color> [impl Into<Option<Color>> as into],
+        background: Option<Color> [impl Into<Option<Color>> as into],
+    ),
+    size: |&self, _| {
+        Ok(Vec2::new(display_width(&self.text)?, 1))
+    },
+    draw: |self, canvas| {
+        canvas
+            .text(&Just::Centered, &self.text)
+            .colored(self.foreground, self.background)
+            .discard_info()
+    },
+}
+
+widget! {
+    /// A [`title`] that's `width` cells wide, cutting the text off with `ellipsis` if it's too long
+    ///
+    /// The text has a space on each side, so at most `width - 2` columns are shown
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ·······
+    /// ·-fo…-· (highlight represented by -)
+    /// ·······
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(7, 3));
+    /// canvas.draw(&Just::Centered, basic::truncated_title("foobar", 5, '…', None, None))?;
+    ///
+    /// // ·······
+    /// // ·-fo…-· (highlight represented by -)
+    /// // ·······
+    /// assert_eq!(canvas.get(&(3, 1))?.text, 'o');
+    /// assert_eq!(canvas.get(&(4, 1))?.text, '…');
+    ///
+    /// // wide characters are cut off by the columns they take up
+    /// let mut canvas = Basic::new(&(7, 3));
+    /// canvas.draw(&Just::Centered, basic::truncated_title("日本語", 5, '…', None, None))?;
+    ///
+    /// // ·······
+    /// // ·-日…-·
+    /// // ·······
+    /// assert_eq!(canvas.get(&(2, 1))?.text, '日');
+    /// assert_eq!(canvas.get(&(4, 1))?.text, '…');
+    /// assert_eq!(canvas.get(&(5, 1))?.text, ' ');
+    /// Ok(())
+    /// # }
+    /// ```
+    name: truncated_title,
+    args: (
+        text: String [impl ToString as to_string],
+        width: usize,
+        ellipsis: char,
+        foreground: Option<Color> [impl Into<Option<Color>> as into],
+        background: Option<Color> [impl Into<Option<Color>> as into],
+    ),
+    size: |&self, _| {
+        check_min_width(Some(self.width), 3, "truncated title")?;
+        let width = self.width.try_into().map_err(|_| Error::TooLarge("text length", self.width))?;
+        Ok(Vec2::new(width, 1))
+    },
+    draw: |self, canvas| {
+        let max_width = canvas.width() - 2;
+        let text = if display_width(&self.text)? > max_width {
+            let mut text = take_width(&self.text, max_width - char_width(self.ellipsis));
+            text.push(self.ellipsis);
+            text
+        } else {
+            self.text
+        };
+        canvas
+            .fill(' ').colored(self.foreground, self.background)
+            .text(&Just::Centered, &text)
+            .discard_info()
+    },
+}
+
 widget! {
     /// A toggleable button
     ///
@@ -122,7 +268,7 @@ widget! {
     /// ·········
     /// ```
     ///
-    /// # Panics
+    /// # Errors
     ///
     /// - If `width` is less than 6
     ///
@@ -157,11 +303,11 @@ widget! {
         truncate_from_end: Option<bool>,
     ),
     size: |&self, _| {
-        if let Some(width) = self.width { assert!(width >= 6); }
+        check_min_width(self.width, 6, "toggle")?;
         Ok(Vec2::new(super::width_or_length(self.width, &self.text, 6)?, 1))
     },
     draw: |self, canvas| {
-        if let Some(width) = self.width { assert!(width >= 6); }
+        check_min_width(self.width, 6, "toggle")?;
 
         canvas.fill(' ').colored(self.foreground, self.background)?;
 
@@ -189,6 +335,8 @@ widget! {
     /// # Optionals
     ///
     /// - [`max_width: usize`](TitledText::max_width)
+    /// - [`text_alignment: TextAlign`](TitledText::text_alignment) (default: [`TextAlign::Center`])
+    /// - [`row_separator: char`](TitledText::row_separator) (default: no separator)
     ///
     /// # Style
     ///
@@ -203,6 +351,43 @@ widget! {
     /// ··---Mocha---··
     /// ···············
     /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(8, 3));
+    /// canvas.draw(&Just::Centered, basic::titled_text("Tree", &["a.rs", "lib.rs"], None, None, None, None)
+    ///     .text_alignment(TextAlign::Left))?;
+    ///
+    /// // ··Tree··
+    /// // ·a.rs···
+    /// // ·lib.rs·
+    /// assert_eq!(canvas.get(&(1, 1))?.text, 'a');
+    /// assert_eq!(canvas.get(&(1, 2))?.text, 'l');
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// With a row separator
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(6, 4));
+    /// canvas.draw(&Just::Centered, basic::titled_text("Tree", &["a", "b"], None, None, None, None)
+    ///     .row_separator('─'))?;
+    ///
+    /// // ·Tree·
+    /// // ··a···
+    /// // ──────
+    /// // ··b···
+    /// assert_eq!(canvas.get(&(0, 2))?.text, '─');
+    /// assert_eq!(canvas.get(&(2, 3))?.text, 'b');
+    /// # Ok(()) }
+    /// ```
     #[allow(clippy::similar_names)] // sorry
     name: titled_text,
     args: (
@@ -215,9 +400,14 @@ widget! {
     ),
     optionals: (
         max_width: Option<usize>,
+        text_alignment: Option<TextAlign>,
+        row_separator: Option<char>,
     ),
     size: |&self, _| {
-        titled_text_bounds(&self.title, &self.text, self.max_width)
+        let size = titled_text_bounds(&self.title, &self.text, self.max_width)?;
+        // one separator in between each row
+        let separators = if self.row_separator.is_some() { size.y - 2 } else { 0 };
+        Ok(size.add_y(separators.max(0)))
     },
     draw: |self, canvas| {
         let width = canvas.width();
@@ -234,10 +424,22 @@ widget! {
             .colored(self.title_fg, self.title_bg)?;
 
         // text
-        for (text, line) in self.text.iter().zip(1..) {
+        let alignment = self.text_alignment.unwrap_or(TextAlign::Center);
+        let step = if self.row_separator.is_some() { 2 } else { 1 };
+        for (row, text) in (0..).zip(&self.text) {
+            let line = 1 + row * step;
+
+            // separate this row from the last
+            if let (Some(separator), true) = (self.row_separator, row > 0) {
+                let separator_line = separator.to_string().repeat(canvas.width_unsigned()?);
+                canvas.text(&Just::CenteredOnRow(line - 1), &separator_line)
+                    .colored(self.text_fg, self.text_bg)?;
+            }
+
             let text = truncate(text, max_width, false);
-            canvas.text(&Just::CenteredOnRow(line), &text)
-                .expand_profile(width, None, GrowFrom::Center)
+            let (justification, grow) = row_alignment(alignment, line, width, length_of(&text)?);
+            canvas.text(&justification, &text)
+                .expand_profile(width, None, grow)
                 .colored(self.text_fg, self.text_bg)?;
         }
 
@@ -245,6 +447,18 @@ widget! {
     },
 }
 
+/// Gets where to place text of length `len` on row `line` of a canvas of width `width`, and how to
+/// grow it to fill the row
+pub(super) const fn row_alignment(alignment: TextAlign, line: isize, width: isize, len: isize) -> (Just, GrowFrom) {
+    // the text is placed one away from the edge, so grow by the space on each side
+    let space = width - len - 1;
+    match alignment {
+        TextAlign::Left => (Just::LeftOfRow(line), GrowFrom::FromEdges { top: 0, right: space, bottom: 0, left: 1 }),
+        TextAlign::Center => (Just::CenteredOnRow(line), GrowFrom::Center),
+        TextAlign::Right => (Just::RightOfRow(line), GrowFrom::FromEdges { top: 0, right: 1, bottom: 0, left: space }),
+    }
+}
+
 pub(super) fn titled_text_bounds(title: &String, text: &Vec<String>, max_width: Option<usize>) -> Result<Vec2, Error> {
     let mut text_width = text.iter()
         .chain(std::iter::once(title))
@@ -272,6 +486,8 @@ widget! {
     /// - [`at_start: bool`](RollingSelection::at_start) (default: false)
     /// - [`at_end: bool`](RollingSelection::at_end) (default: false)
     /// - [`truncate_from_end: bool`](RollingSelection::truncate_from_end) (default: false)
+    /// - [`arrow_chars: (char, char)`](RollingSelection::arrow_chars) (default: ('←', '→'))
+    /// - [`center_label: bool`](RollingSelection::center_label) (default: true)
     ///
     /// # Style
     ///
@@ -280,6 +496,51 @@ widget! {
     /// · ← foo → ·
     /// ···········
     /// ```
+    ///
+    /// # Errors
+    ///
+    /// - If `width` is less than 6
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use widgets::basic;
+    /// # fn main() {
+    /// let mut canvas = Basic::new(&(9, 3));
+    /// let res = canvas.draw(&Just::Centered, basic::rolling_selection("foo", 4, None, None));
+    /// assert!(matches!(res, Err(Error::ItemTooBig { name: "rolling_selection", .. })));
+    /// # }
+    /// ```
+    ///
+    /// With ascii arrows
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(9, 1));
+    /// canvas.draw(&Just::Centered, basic::rolling_selection("foo", None, None, None).arrow_chars(('<', '>')))?;
+    ///
+    /// // ·<·foo·>·
+    /// assert_eq!(canvas.get(&(1, 0))?.text, '<');
+    /// assert_eq!(canvas.get(&(7, 0))?.text, '>');
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// With the label on the left
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(11, 1));
+    /// canvas.draw(&Just::Centered, basic::rolling_selection("foo", 11, None, None).center_label(false))?;
+    ///
+    /// // ·←·foo···→·
+    /// assert_eq!(canvas.get(&(3, 0))?.text, 'f');
+    /// # Ok(()) }
+    /// ```
     name: rolling_selection,
     args: (
         text: String [impl ToString as to_string],
@@ -292,29 +553,716 @@ widget! {
         at_start: Option<bool>,
         at_end: Option<bool>,
         truncate_from_end: Option<bool>,
+        arrow_chars: Option<(char, char)>,
+        center_label: Option<bool>,
     ),
     size: |&self, _| {
+        check_min_width(self.width, 6, "rolling_selection")?;
         let width = self.width.unwrap_or_else(|| self.text.chars().count() + 6);
         let width: isize = width.try_into()
             .map_err(|_| Error::TooLarge("text length", width))?;
         Ok(Vec2::new(width, 1))
     },
     draw: |self, canvas| {
-        assert!(!self.width.is_some_and(|width| width < 6), "rolling selection width must be at least 6");
+        check_min_width(self.width, 6, "rolling_selection")?;
 
         let text = truncate(&self.text, self.width.map(|val| val - 6), self.truncate_from_end.unwrap_or_default());
+        // otherwise put the label right after the left arrow
+        let justification = if self.center_label.unwrap_or(true) { Just::Centered } else { Just::OffCenterLeftBy(3) };
         canvas
             .fill(' ').colored(self.foreground, self.background)
-            .text(&Just::Centered, &text)?;
+            .text(&justification, &text)?;
+
+        let (left, right) = self.arrow_chars.unwrap_or(('←', '→'));
 
         if !self.at_start.unwrap_or_default() {
-            canvas.text(&Just::CenterLeft, "←")?;
+            canvas.text(&Just::CenterLeft, &left.to_string())?;
         }
 
         if !self.at_end.unwrap_or_default() {
-            canvas.text(&Just::CenterRight, "→")?;
+            canvas.text(&Just::CenterRight, &right.to_string())?;
+        }
+
+        Ok(())
+    },
+}
+
+widget! {
+    /// A labeled bar filled according to `value` out of `max`
+    ///
+    /// `width` is the width of the bar not including the brackets
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ············
+    /// ····foo·····
+    /// ·[▓▓▓░░░░]·· (filled cells are highlighted with `filled_fg` and `filled_bg`)
+    /// ············
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(10, 2));
+    /// canvas.draw(&Just::Centered, basic::gauge("fuel", 3.0, 4.0, 8, Frappe::green(), Frappe::base(), Frappe::surface2()))?;
+    ///
+    /// // ···fuel···
+    /// // [▓▓▓▓▓▓░░]
+    /// assert_eq!(canvas.get(&(3, 0))?.text, 'f');
+    /// assert_eq!(canvas.get(&(6, 1))?.text, '▓');
+    /// assert_eq!(canvas.get(&(6, 1))?.foreground, Some(Frappe::green()));
+    /// assert_eq!(canvas.get(&(7, 1))?.text, '░');
+    /// Ok(())
+    /// # }
+    /// ```
+    name: gauge,
+    args: (
+        label: String [impl ToString as to_string],
+        value: f32,
+        max: f32,
+        width: usize,
+        filled_fg: Option<Color> [impl Into<Option<Color>> as into],
+        filled_bg: Option<Color> [impl Into<Option<Color>> as into],
+        empty_fg: Option<Color> [impl Into<Option<Color>> as into],
+    ),
+    size: |&self, _| {
+        let width: isize = self.width.try_into()
+            .map_err(|_| Error::TooLarge("gauge width", self.width))?;
+        // leave room for the brackets
+        Ok(Vec2::new(width.max(length_of(&self.label)? - 2) + 2, 2))
+    },
+    draw: |self, canvas| {
+        let width = canvas.width() - 2;
+        let filled = filled_cells(self.value, self.max, width);
+
+        canvas.text(&Just::CenteredOnRow(0), &self.label)?;
+        canvas.set(&(0, 1), '[')?;
+        canvas.set(&(width + 1, 1), ']')?;
+        for x in 1..=width {
+            if x <= filled {
+                canvas.set(&(x, 1), '▓').colored(self.filled_fg, self.filled_bg)?;
+            } else {
+                canvas.set(&(x, 1), '░').foreground(self.empty_fg)?;
+            }
+        }
+
+        Ok(())
+    },
+}
+
+/// Gets the amount of cells out of `width` that should be filled to represent `value` out of `max`
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+fn filled_cells(value: f32, max: f32, width: isize) -> isize {
+    if max <= 0.0 { return 0; }
+    let ratio = (value / max).clamp(0.0, 1.0);
+    (ratio * width as f32).round() as isize
+}
+
+widget! {
+    /// A small bar chart of `data` without any axes
+    ///
+    /// Only the last `width` values are shown, scaled between the smallest and largest of them
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ··········
+    /// ·▁▂▄▃▆█▇▅·
+    /// ··········
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(4, 1));
+    /// canvas.draw(&Just::Centered, basic::sparkline(&[7.0, 0.0, 6.0, 14.0], 4, None, None))?;
+    ///
+    /// // ▅▁▄█
+    /// assert_eq!(canvas.get(&(0, 0))?.text, '▅');
+    /// assert_eq!(canvas.get(&(1, 0))?.text, '▁');
+    /// assert_eq!(canvas.get(&(3, 0))?.text, '█');
+    /// Ok(())
+    /// # }
+    /// ```
+    name: sparkline,
+    args: (
+        data: Vec<f32> [&[f32] > .to_vec()],
+        width: usize,
+        foreground: Option<Color> [impl Into<Option<Color>> as into],
+        background: Option<Color> [impl Into<Option<Color>> as into],
+    ),
+    size: |&self, _| {
+        let width: isize = self.width.try_into()
+            .map_err(|_| Error::TooLarge("sparkline width", self.width))?;
+        Ok(Vec2::new(width, 1))
+    },
+    draw: |self, canvas| {
+        const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+        canvas.fill(' ').colored(self.foreground, self.background)?;
+
+        // only show the most recent values that fit
+        let width = canvas.width_unsigned()?;
+        let data = &self.data[self.data.len().saturating_sub(width)..];
+
+        let min = data.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = data.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let range = max - min;
+
+        // right align the bars
+        let offset = width - data.len();
+        for (value, x) in data.iter().zip(offset..) {
+            let level = if range > 0.0 { (value - min) / range } else { 0.0 };
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let bar = BARS[(level * 7.0).round() as usize];
+            let x: isize = x.try_into().map_err(|_| Error::TooLarge("sparkline width", x))?;
+            canvas.set(&(x, 0), bar)?;
         }
 
         Ok(())
     },
 }
+
+/// Where a [`notification`] is placed on the canvas
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum NotificationPos {
+    #[default]
+    Top,
+    Bottom,
+    Custom(Just),
+}
+
+widget! {
+    /// A boxed message shown on top of the rest of the canvas
+    ///
+    /// The widget takes up the entire canvas, and the message is placed within it according to
+    /// `position`
+    ///
+    /// # Optionals
+    ///
+    /// - [`position: NotificationPos`](Notification::position) (default: [`NotificationPos::Top`])
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ·············
+    /// ···┌─────┐···
+    /// ···│-foo-│··· (highlight represented by -)
+    /// ···└─────┘···
+    /// ·············
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    /// use widgets::basic::{self, NotificationPos};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(9, 5));
+    /// canvas.draw(&Just::Centered, basic::notification("foo", Frappe::base(), Frappe::green())
+    ///     .position(NotificationPos::Bottom))?;
+    ///
+    /// // ·········
+    /// // ·┌─────┐·
+    /// // ·│-foo-│· (highlight represented by -)
+    /// // ·└─────┘·
+    /// // ·········
+    /// assert_eq!(canvas.get(&(1, 1))?.text, '┌');
+    /// assert_eq!(canvas.get(&(3, 2))?.text, 'f');
+    /// assert_eq!(canvas.get(&(3, 2))?.background, Some(Frappe::green()));
+    /// Ok(())
+    /// # }
+    /// ```
+    name: notification,
+    args: (
+        text: String [impl ToString as to_string],
+        foreground: Option<Color> [impl Into<Option<Color>> as into],
+        background: Option<Color> [impl Into<Option<Color>> as into],
+    ),
+    optionals: (
+        position: Option<NotificationPos>,
+    ),
+    size: |&self, canvas_size| {
+        Ok(Vec2::from_size(canvas_size))
+    },
+    draw: |self, canvas| {
+        let justification = match self.position.unwrap_or_default() {
+            NotificationPos::Top => Just::CenterTop,
+            NotificationPos::Bottom => Just::CenterBottom,
+            NotificationPos::Custom(justification) => justification,
+        };
+        canvas.draw_box(&justification, &box_chars::LIGHT, title(&self.text, None, None))
+            .colored(self.foreground, self.background)
+            .discard_info()
+    },
+}
+
+widget! {
+    /// A single symbol or emoji, taking up as many cells as it's displayed with
+    ///
+    /// Wide characters take up two cells, with the second filled with a [`WIDE_CHAR_PLACEHOLDER`](crate::canvas::WIDE_CHAR_PLACEHOLDER)
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ····
+    /// ·✓··
+    /// ·🔴·
+    /// ····
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use canvas_tui::canvas::WIDE_CHAR_PLACEHOLDER;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(4, 3));
+    /// let info = canvas.draw(&Just::Centered, basic::icon('🔴', Color::WHITE, None))?;
+    /// assert_eq!(info.shape.size, (2, 1));
+    ///
+    /// // ····
+    /// // ·🔴·
+    /// // ····
+    /// assert_eq!(canvas.get(&(1, 1))?.text, '🔴');
+    /// assert_eq!(canvas.get(&(2, 1))?.text, WIDE_CHAR_PLACEHOLDER);
+    /// assert_eq!(canvas.get(&(2, 1))?.foreground, Some(Color::WHITE));
+    /// Ok(())
+    /// # }
+    /// ```
+    name: icon,
+    args: (
+        chr: char,
+        foreground: Option<Color> [impl Into<Option<Color>> as into],
+        background: Option<Color> [impl Into<Option<Color>> as into],
+    ),
+    size: |&self, _| {
+        Ok(Vec2::new(char_width(self.chr), 1))
+    },
+    draw: |self, canvas| {
+        canvas
+            .fill(' ').colored(self.foreground, self.background)
+            .text_absolute(&(0, 0), &self.chr.to_string())
+            .discard_info()
+    },
+}
+
+widget! {
+    /// A list of keys and their values, with the keys and values each lined up in a column
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ················
+    /// ·-name  :=foo =· (highlights represented by - and =)
+    /// ·-count :=12  =·
+    /// ·-path  :=/a/b=·
+    /// ················
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(16, 4));
+    /// let items = [("name", "foo"), ("count", "12")];
+    /// canvas.draw(&Just::Centered, basic::data_list(&items, Frappe::text(), Frappe::surface2(), Frappe::text(), Frappe::surface0()))?;
+    ///
+    /// // ················
+    /// // ·-name  :=foo =· (highlights represented by - and =)
+    /// // ·-count :=12  =·
+    /// // ················
+    /// assert_eq!(canvas.get(&(2, 1))?.text, 'n');
+    /// assert_eq!(canvas.get(&(8, 1))?.text, ':');
+    /// assert_eq!(canvas.get(&(8, 1))?.background, Some(Frappe::surface2()));
+    /// assert_eq!(canvas.get(&(10, 2))?.text, '1');
+    /// assert_eq!(canvas.get(&(10, 2))?.background, Some(Frappe::surface0()));
+    /// Ok(())
+    /// # }
+    /// ```
+    name: data_list,
+    args: (
+        items: Vec<(String, String)> [&[(&str, &str)] > .iter().map(|(key, value)| ((*key).to_string(), (*value).to_string())).collect()],
+        key_fg: Option<Color> [impl Into<Option<Color>> as into],
+        key_bg: Option<Color> [impl Into<Option<Color>> as into],
+        value_fg: Option<Color> [impl Into<Option<Color>> as into],
+        value_bg: Option<Color> [impl Into<Option<Color>> as into],
+    ),
+    size: |&self, _| {
+        let (key_width, value_width) = self.column_widths()?;
+        let rows = self.items.len();
+        let rows = rows.try_into().map_err(|_| Error::TooLarge("data list rows", rows))?;
+        Ok(Vec2::new(key_width + value_width + 5, rows))
+    },
+    draw: |self, canvas| {
+        let (key_width, value_width) = self.column_widths()?;
+        for ((key, value), y) in self.items.iter().zip(0..) {
+            // key column: " key :"
+            canvas.highlight_box(&(0, y), &(key_width + 3, 1), self.key_fg, self.key_bg)?;
+            canvas.text_absolute(&(1, y), key)?;
+            canvas.set(&(key_width + 2, y), ':')?;
+            // value column: " value "
+            canvas.highlight_box(&(key_width + 3, y), &(value_width + 2, 1), self.value_fg, self.value_bg)?;
+            canvas.text_absolute(&(key_width + 4, y), value)?;
+        }
+        Ok(())
+    },
+}
+
+impl DataList {
+    /// The display width of the widest key and widest value
+    fn column_widths(&self) -> Result<(isize, isize), Error> {
+        let mut widths = (0, 0);
+        for (key, value) in &self.items {
+            widths.0 = display_width(key)?.max(widths.0);
+            widths.1 = display_width(value)?.max(widths.1);
+        }
+        Ok(widths)
+    }
+}
+
+widget! {
+    /// A path of segments such as `Home > Section > Page`, with the last segment highlighted as
+    /// the current one
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ·······················
+    /// ·Home > Section > Page· (Page colored with active_fg)
+    /// ·······················
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(12, 3));
+    /// canvas.draw(&Just::Centered, basic::breadcrumb(&["a", "bc", "d"], " > ", Frappe::subtext0(), Frappe::text()))?;
+    ///
+    /// // ············
+    /// // ·a > bc > d· (d colored with active_fg)
+    /// // ············
+    /// assert_eq!(canvas.get(&(1, 1))?.text, 'a');
+    /// assert_eq!(canvas.get(&(3, 1))?.foreground, Some(Frappe::subtext0()));
+    /// assert_eq!(canvas.get(&(10, 1))?.text, 'd');
+    /// assert_eq!(canvas.get(&(10, 1))?.foreground, Some(Frappe::text()));
+    /// Ok(())
+    /// # }
+    /// ```
+    name: breadcrumb,
+    args: (
+        segments: Vec<String> [&[impl ToString] > .iter().map(ToString::to_string).collect()],
+        separator: String [&str as to_string],
+        normal_fg: Option<Color> [impl Into<Option<Color>> as into],
+        active_fg: Option<Color> [impl Into<Option<Color>> as into],
+    ),
+    size: |&self, _| {
+        let separators = self.segments.len().saturating_sub(1);
+        let separators = isize::try_from(separators).map_err(|_| Error::TooLarge("breadcrumb segments", separators))?;
+        let mut width = separators * display_width(&self.separator)?;
+        for segment in &self.segments {
+            width += display_width(segment)?;
+        }
+        Ok(Vec2::new(width, 1))
+    },
+    draw: |self, canvas| {
+        let last = self.segments.len().saturating_sub(1);
+        let mut x = 0;
+        for (index, segment) in self.segments.iter().enumerate() {
+            if index > 0 {
+                x += canvas.text_absolute(&(x, 0), &self.separator).foreground(self.normal_fg)?.shape.size.x;
+            }
+            let foreground = if index == last { self.active_fg } else { self.normal_fg };
+            x += canvas.text_absolute(&(x, 0), segment).foreground(foreground)?.shape.size.x;
+        }
+        Ok(())
+    },
+}
+
+widget! {
+    /// A row of a menu with a `label` on the left and a `shortcut` on the right, `width` cells wide
+    ///
+    /// # Errors
+    ///
+    /// - If `width` can't fit the label, the shortcut, and a space around and between them
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ·····················
+    /// ·-Open File  Ctrl+O-· (highlight represented by -)
+    /// ·····················
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(16, 3));
+    /// canvas.draw(&Just::Centered, basic::menu_item("Open", "Ctrl+O", 14, None, Color::WHITE))?;
+    ///
+    /// // ················
+    /// // ·-Open  Ctrl+O-· (highlight represented by -)
+    /// // ················
+    /// assert_eq!(canvas.get(&(2, 1))?.text, 'O');
+    /// assert_eq!(canvas.get(&(8, 1))?.text, 'C');
+    /// assert_eq!(canvas.get(&(7, 1))?.background, Some(Color::WHITE));
+    /// Ok(())
+    /// # }
+    /// ```
+    name: menu_item,
+    args: (
+        label: String [&str as to_string],
+        shortcut: String [&str as to_string],
+        width: usize,
+        foreground: Option<Color> [impl Into<Option<Color>> as into],
+        background: Option<Color> [impl Into<Option<Color>> as into],
+    ),
+    size: |&self, _| {
+        let min = (display_width(&self.label)? + display_width(&self.shortcut)? + 3).unsigned_abs();
+        check_min_width(Some(self.width), min, "menu_item")?;
+        let width = self.width.try_into().map_err(|_| Error::TooLarge("menu item width", self.width))?;
+        Ok(Vec2::new(width, 1))
+    },
+    draw: |self, canvas| {
+        canvas.fill(' ').colored(self.foreground, self.background)?;
+        canvas.text_absolute(&(1, 0), &self.label)?;
+        canvas.text_right(&Just::CenterRight, &self.shortcut)?;
+        Ok(())
+    },
+}
+
+widget! {
+    /// A strip across the whole width of the canvas with text on the `left`, `center`, and `right`,
+    /// such as the mode, position, and status at the bottom of the screen
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ·····················
+    /// ·····················
+    /// -NOR---foo.rs----1:1- (highlight represented by -)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(15, 3));
+    /// canvas.draw(&Just::OffBottomLeftBy(0), basic::status_bar("NOR", "a.rs", "1:1", None, Color::WHITE))?;
+    ///
+    /// // ···············
+    /// // ···············
+    /// // -NOR-a.rs--1:1- (highlight represented by -)
+    /// assert_eq!(canvas.get(&(1, 2))?.text, 'N');
+    /// assert_eq!(canvas.get(&(5, 2))?.text, 'a');
+    /// assert_eq!(canvas.get(&(11, 2))?.text, '1');
+    /// assert_eq!(canvas.get(&(0, 2))?.background, Some(Color::WHITE));
+    /// assert_eq!(canvas.get(&(14, 2))?.background, Some(Color::WHITE));
+    /// Ok(())
+    /// # }
+    /// ```
+    name: status_bar,
+    args: (
+        left: String [&str as to_string],
+        center: String [&str as to_string],
+        right: String [&str as to_string],
+        foreground: Option<Color> [impl Into<Option<Color>> as into],
+        background: Option<Color> [impl Into<Option<Color>> as into],
+    ),
+    size: |&self, canvas_size| {
+        Ok(Vec2::new(canvas_size.width(), 1))
+    },
+    draw: |self, canvas| {
+        canvas
+            .fill(' ').colored(self.foreground, self.background)
+            .text(&Just::LeftOfRow(0), &self.left)
+            .text(&Just::CenteredOnRow(0), &self.center)
+            .text(&Just::RightOfRow(0), &self.right)
+            .discard_info()
+    },
+}
+
+widget! {
+    /// A box showing the text being typed and the cursor at `cursor_pos`, `width` cells wide
+    ///
+    /// If the text doesn't fit, it scrolls so the cursor is always shown
+    ///
+    /// # Errors
+    ///
+    /// - If `width` is less than 3
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ···········
+    /// ·-foo=----· (highlight represented by -, cursor represented by =)
+    /// ···········
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(9, 3));
+    /// canvas.draw(&Just::Centered, basic::input_field("foo", 1, 7, None, None, Color::BLACK, Color::WHITE))?;
+    ///
+    /// // ·········
+    /// // ·-foo---· (cursor on the first o)
+    /// // ·········
+    /// assert_eq!(canvas.get(&(3, 1))?.text, 'o');
+    /// assert_eq!(canvas.get(&(3, 1))?.background, Some(Color::WHITE));
+    /// assert_eq!(canvas.get(&(4, 1))?.background, None);
+    /// Ok(())
+    /// # }
+    /// ```
+    name: input_field,
+    args: (
+        text: String [&str as to_string],
+        cursor_pos: usize,
+        width: usize,
+        foreground: Option<Color> [impl Into<Option<Color>> as into],
+        background: Option<Color> [impl Into<Option<Color>> as into],
+        cursor_fg: Option<Color> [impl Into<Option<Color>> as into],
+        cursor_bg: Option<Color> [impl Into<Option<Color>> as into],
+    ),
+    size: |&self, _| {
+        check_min_width(Some(self.width), 3, "input_field")?;
+        let width = self.width.try_into().map_err(|_| Error::TooLarge("input field width", self.width))?;
+        Ok(Vec2::new(width, 1))
+    },
+    draw: |self, canvas| {
+        check_min_width(Some(self.width), 3, "input_field")?;
+        // leave a space on each side
+        let inner = self.width - 2;
+        // scroll just far enough to show the cursor
+        let start = self.cursor_pos.saturating_sub(inner - 1);
+        let shown: String = self.text.chars().skip(start).take(inner).collect();
+
+        canvas
+            .fill(' ').colored(self.foreground, self.background)
+            .text_absolute(&(1, 0), &shown)?;
+
+        let cursor = isize::try_from(self.cursor_pos - start + 1)
+            .map_err(|_| Error::TooLarge("cursor position", self.cursor_pos))?;
+        canvas.highlight(&(cursor, 0), self.cursor_fg, self.cursor_bg)?;
+        Ok(())
+    },
+}
+
+widget! {
+    /// A bordered box with an accent strip down the left side, an `icon` and `title`, and the
+    /// lines of `body` underneath
+    ///
+    /// The box is sized to fit the title and the widest line of the body
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ························
+    /// ·┌────────────────────┐·
+    /// ·│- ⚠ Warning         │· (accent represented by -)
+    /// ·│- disk space low    │·
+    /// ·│- try deleting logs │·
+    /// ·└────────────────────┘·
+    /// ························
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    /// use widgets::basic;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Basic::new(&(15, 6));
+    /// let info = canvas.draw(&Just::Centered, basic::callout(
+    ///     "Warning", "disk\nspace low", '⚠',
+    ///     Frappe::yellow(), Frappe::yellow(), Frappe::text(), Frappe::base()
+    /// ))?;
+    /// assert_eq!(info.shape.size, (14, 5));
+    ///
+    /// // ┌────────────┐·
+    /// // │- ⚠ Warning │· (accent represented by -)
+    /// // │- disk      │·
+    /// // │- space low │·
+    /// // └────────────┘·
+    /// // ···············
+    /// assert_eq!(canvas.get(&(1, 2))?.background, Some(Frappe::yellow()));
+    /// assert_eq!(canvas.get(&(3, 1))?.text, '⚠');
+    /// assert_eq!(canvas.get(&(5, 1))?.foreground, Some(Frappe::yellow()));
+    /// assert_eq!(canvas.get(&(5, 1))?.background, Some(Frappe::base()));
+    /// assert_eq!(canvas.get(&(3, 3))?.text, 's');
+    /// assert_eq!(canvas.get(&(3, 3))?.foreground, Some(Frappe::text()));
+    /// Ok(())
+    /// # }
+    /// ```
+    name: callout,
+    args: (
+        title: String [impl ToString as to_string],
+        body: String [impl ToString as to_string],
+        icon: char,
+        accent_fg: Option<Color> [impl Into<Option<Color>> as into],
+        accent_bg: Option<Color> [impl Into<Option<Color>> as into],
+        body_fg: Option<Color> [impl Into<Option<Color>> as into],
+        body_bg: Option<Color> [impl Into<Option<Color>> as into],
+    ),
+    size: |&self, _| {
+        let rows = self.body.lines().count();
+        let rows: isize = rows.try_into().map_err(|_| Error::TooLarge("callout rows", rows))?;
+        // the border, the accent strip, and a space on each side of the text
+        Ok(Vec2::new(self.content_width()? + 5, rows + 3))
+    },
+    draw: |self, canvas| {
+        let size = Vec2::from_size(canvas);
+        canvas
+            .fill(' ').colored(self.body_fg, self.body_bg)
+            .rect_absolute(&(0, 0), &size, &box_chars::LIGHT)
+            .highlight_box(&(1, 1), &(1, size.y - 2), self.accent_fg, self.accent_bg)
+            .text_absolute(&(3, 1), &self.header())
+                .colored(self.accent_fg, self.body_bg)?;
+        for (line, y) in self.body.lines().zip(2..) {
+            canvas.text_absolute(&(3, y), line)?;
+        }
+        Ok(())
+    },
+}
+
+impl Callout {
+    /// The icon and the title, shown on the first row
+    fn header(&self) -> String {
+        format!("{} {}", self.icon, self.title)
+    }
+
+    /// The display width of the header or the widest line of the body, whichever is wider
+    fn content_width(&self) -> Result<isize, Error> {
+        let mut width = display_width(&self.header())?;
+        for line in self.body.lines() {
+            width = display_width(line)?.max(width);
+        }
+        Ok(width)
+    }
+}
@@ -0,0 +1,450 @@
+//! Widgets that arrange other widgets, such as [`hstack`] and [`vstack`]
+//!
+//! The stacks take in [boxed widgets](WidgetSource::boxed), so different types of widgets can
+//! be arranged together, while wrappers like [`padded`] take in any single widget
+//!
+//! # Example
+//!
+//! ```
+//! use canvas_tui::prelude::*;
+//! use widgets::{basic, layout};
+//!
+//! fn main() -> Result<(), Error> {
+//!     let mut canvas = Basic::new(&(13, 3));
+//!     canvas.draw(&Just::Centered, layout::hstack(vec![
+//!         basic::title("foo", None, None).boxed(),
+//!         basic::title("bar", None, None).boxed(),
+//!     ], 1))?;
+//!
+//!     // .............
+//!     // . foo   bar .
+//!     // .............
+//!     assert_eq!(canvas.get(&(2, 1))?.text, 'f');
+//!     assert_eq!(canvas.get(&(8, 1))?.text, 'b');
+//!     Ok(())
+//! }
+//! ```
+
+use crate::prelude::*;
+use widgets::prelude::*;
+
+/// The vertical alignment of a widget within a row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VAlign {
+    #[default]
+    Top,
+    Center,
+    Bottom,
+}
+
+impl VAlign {
+    /// Gets the y position of an object of height `object` in a space of height `space`
+    #[must_use]
+    pub const fn position(self, space: isize, object: isize) -> isize {
+        match self {
+            Self::Top => 0,
+            Self::Center => (space - object) / 2,
+            Self::Bottom => space - object,
+        }
+    }
+}
+
+/// The horizontal alignment of a widget within a column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+impl HAlign {
+    /// Gets the x position of an object of width `object` in a space of width `space`
+    #[must_use]
+    pub const fn position(self, space: isize, object: isize) -> isize {
+        match self {
+            Self::Left => 0,
+            Self::Center => (space - object) / 2,
+            Self::Right => space - object,
+        }
+    }
+}
+
+/// Converts the spacing between widgets into an [`isize`]
+fn spacing_of(spacing: usize) -> Result<isize, Error> {
+    spacing.try_into().map_err(|_| Error::TooLarge("spacing", spacing))
+}
+
+/// See [`hstack`]
+pub struct HStack<'a> {
+    widgets: Vec<Box<dyn Widget + 'a>>,
+    spacing: usize,
+    alignment: Option<VAlign>,
+}
+
+/// Places `widgets` side by side with `spacing` empty columns in between
+///
+/// # Optionals
+///
+/// - [`alignment: VAlign`](HStack::alignment) (default: [`VAlign::Top`])
+///
+/// # Style
+///
+/// The width is the sum of the widths of the widgets and the spacing, and the height is the height
+/// of the tallest widget
+///
+/// ```text
+/// ·············
+/// ·-foo-·-bar-· (spacing represented by ·)
+/// ·············
+/// ```
+///
+/// # Example
+///
+/// See the [outer module's example](self)
+#[must_use]
+pub fn hstack<'a>(widgets: Vec<Box<dyn Widget + 'a>>, spacing: usize) -> HStack<'a> {
+    HStack { widgets, spacing, alignment: None }
+}
+
+impl<'a> HStack<'a> {
+    #[must_use]
+    pub fn alignment(self, alignment: VAlign) -> Self {
+        Self { alignment: Some(alignment), ..self }
+    }
+}
+
+impl<'a> Widget for HStack<'a> {
+    fn size(&self, canvas_size: &impl Size) -> Result<Vec2, Error> {
+        let spacing = spacing_of(self.spacing)?;
+        let mut size = Vec2::new(0, 0);
+        for (i, widget) in self.widgets.iter().enumerate() {
+            let widget = widget.size(canvas_size)?;
+            let gap = if i == 0 { 0 } else { spacing };
+            size = Vec2::new(size.x + gap + widget.x, size.y.max(widget.y));
+        }
+        Ok(size)
+    }
+
+    fn draw<C: Canvas>(self, canvas: &mut C) -> Result<(), Error> {
+        let spacing = spacing_of(self.spacing)?;
+        let alignment = self.alignment.unwrap_or_default();
+        let height = canvas.height();
+
+        let mut x = 0;
+        for widget in self.widgets {
+            let size = widget.size(canvas)?;
+            let y = alignment.position(height, size.y);
+            canvas.draw(&Just::At(Vec2::new(x, y)), widget)?;
+            x += size.x + spacing;
+        }
+
+        Ok(())
+    }
+
+    fn name() -> &'static str { "hstack" }
+}
+
+/// See [`vstack`]
+pub struct VStack<'a> {
+    widgets: Vec<Box<dyn Widget + 'a>>,
+    spacing: usize,
+    alignment: Option<HAlign>,
+}
+
+/// Stacks `widgets` on top of each other with `spacing` empty rows in between
+///
+/// # Optionals
+///
+/// - [`alignment: HAlign`](VStack::alignment) (default: [`HAlign::Left`])
+///
+/// # Style
+///
+/// The width is the width of the widest widget, and the height is the sum of the heights of the
+/// widgets and the spacing
+///
+/// ```text
+/// ·······
+/// ·-foo-·
+/// ······· (spacing represented by ·)
+/// ·-bar-·
+/// ·······
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use canvas_tui::prelude::*;
+/// use widgets::{basic, layout::{self, HAlign}};
+///
+/// fn main() -> Result<(), Error> {
+///     let mut canvas = Basic::new(&(7, 5));
+///     canvas.draw(&Just::Centered, layout::vstack(vec![
+///         basic::title("foo", None, None).boxed(),
+///         basic::title("b", None, None).boxed(),
+///     ], 1).alignment(HAlign::Center))?;
+///
+///     // .......
+///     // ..foo..
+///     // .......
+///     // ...b...
+///     // .......
+///     assert_eq!(canvas.get(&(2, 1))?.text, 'f');
+///     assert_eq!(canvas.get(&(3, 3))?.text, 'b');
+///     Ok(())
+/// }
+/// ```
+#[must_use]
+pub fn vstack<'a>(widgets: Vec<Box<dyn Widget + 'a>>, spacing: usize) -> VStack<'a> {
+    VStack { widgets, spacing, alignment: None }
+}
+
+impl<'a> VStack<'a> {
+    #[must_use]
+    pub fn alignment(self, alignment: HAlign) -> Self {
+        Self { alignment: Some(alignment), ..self }
+    }
+}
+
+impl<'a> Widget for VStack<'a> {
+    fn size(&self, canvas_size: &impl Size) -> Result<Vec2, Error> {
+        let spacing = spacing_of(self.spacing)?;
+        let mut size = Vec2::new(0, 0);
+        for (i, widget) in self.widgets.iter().enumerate() {
+            let widget = widget.size(canvas_size)?;
+            let gap = if i == 0 { 0 } else { spacing };
+            size = Vec2::new(size.x.max(widget.x), size.y + gap + widget.y);
+        }
+        Ok(size)
+    }
+
+    fn draw<C: Canvas>(self, canvas: &mut C) -> Result<(), Error> {
+        let spacing = spacing_of(self.spacing)?;
+        let alignment = self.alignment.unwrap_or_default();
+        let width = canvas.width();
+
+        let mut y = 0;
+        for widget in self.widgets {
+            let size = widget.size(canvas)?;
+            let x = alignment.position(width, size.x);
+            canvas.draw(&Just::At(Vec2::new(x, y)), widget)?;
+            y += size.y + spacing;
+        }
+
+        Ok(())
+    }
+
+    fn name() -> &'static str { "vstack" }
+}
+
+/// See [`padded`]
+pub struct Padded<W: Widget> {
+    inner: W,
+    top: isize,
+    right: isize,
+    bottom: isize,
+    left: isize,
+}
+
+/// Surrounds `inner` with empty space on each side
+///
+/// # Style
+///
+/// ```text
+/// ·······
+/// ·-foo-· (padding represented by ·)
+/// ·······
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use canvas_tui::prelude::*;
+/// use widgets::{basic, layout};
+///
+/// fn main() -> Result<(), Error> {
+///     let mut canvas = Basic::new(&(7, 3));
+///     canvas.draw(&Just::Centered, layout::padded(basic::title("foo", None, None), 1, 0, 1, 2))?;
+///
+///     // .......
+///     // ... foo
+///     // .......
+///     assert_eq!(canvas.get(&(3, 1))?.text, 'f');
+///     Ok(())
+/// }
+/// ```
+#[must_use]
+pub fn padded<W: WidgetSource>(inner: W, top: isize, right: isize, bottom: isize, left: isize) -> Padded<W::Output> {
+    Padded { inner: inner.build(), top, right, bottom, left }
+}
+
+impl<W: Widget> Padded<W> {
+    /// The total size of the padding
+    const fn padding(&self) -> Vec2 {
+        Vec2::new(self.left + self.right, self.top + self.bottom)
+    }
+}
+
+impl<W: Widget> Widget for Padded<W> {
+    fn size(&self, canvas_size: &impl Size) -> Result<Vec2, Error> {
+        let padding = self.padding();
+        Ok(self.inner.size(&(Vec2::from_size(canvas_size) - padding))? + padding)
+    }
+
+    fn draw<C: Canvas>(self, canvas: &mut C) -> Result<(), Error> {
+        let size = Vec2::from_size(canvas) - self.padding();
+        self.inner.draw(&mut canvas.window_absolute(&Vec2::new(self.left, self.top), &size)?)
+    }
+
+    fn name() -> &'static str { "padded" }
+}
+
+/// See [`aligned`]
+pub struct Aligned<W: Widget> {
+    inner: W,
+    size: Vec2,
+    justification: Just,
+}
+
+/// Reserves a space of `size` and places `inner` within it using `justification`
+///
+/// Useful for making a group of widgets take up the same amount of space regardless of their
+/// content, such as items in a menu
+///
+/// # Style
+///
+/// ```text
+/// ·······
+/// ··-a-·· (reserved space represented by ·)
+/// ·······
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use canvas_tui::prelude::*;
+/// use widgets::{basic, layout};
+///
+/// fn main() -> Result<(), Error> {
+///     let mut canvas = Basic::new(&(9, 2));
+///     canvas.draw(&Just::Centered, layout::vstack(vec![
+///         layout::aligned(basic::title("a", None, None), &(7, 1), Just::CenterLeft).boxed(),
+///         layout::aligned(basic::title("bcd", None, None), &(7, 1), Just::CenterLeft).boxed(),
+///     ], 0))?;
+///
+///     // ... a ...
+///     // ... bcd .
+///     assert_eq!(canvas.get(&(3, 0))?.text, 'a');
+///     assert_eq!(canvas.get(&(3, 1))?.text, 'b');
+///     Ok(())
+/// }
+/// ```
+#[must_use]
+pub fn aligned<W: WidgetSource>(inner: W, size: &impl Size, justification: Just) -> Aligned<W::Output> {
+    Aligned { inner: inner.build(), size: Vec2::from_size(size), justification }
+}
+
+impl<W: Widget> Widget for Aligned<W> {
+    fn size(&self, _: &impl Size) -> Result<Vec2, Error> {
+        Ok(self.size)
+    }
+
+    fn draw<C: Canvas>(self, canvas: &mut C) -> Result<(), Error> {
+        canvas.draw(&self.justification, self.inner)?;
+        Ok(())
+    }
+
+    fn name() -> &'static str { "aligned" }
+}
+
+/// See [`bordered`]
+pub struct Bordered<W: Widget> {
+    inner: W,
+    chars: &'static box_chars::Chars,
+    title: Option<String>,
+}
+
+/// Surrounds `inner` with a border made of `chars`
+///
+/// # Optionals
+///
+/// - [`title: String`](Bordered::title) (default: no title)
+///
+/// # Style
+///
+/// ```text
+/// ┌─bar─┐
+/// │-foo-│
+/// └─────┘
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use canvas_tui::prelude::*;
+/// use widgets::{basic, layout};
+///
+/// fn main() -> Result<(), Error> {
+///     let mut canvas = Basic::new(&(7, 3));
+///     canvas.draw(&Just::Centered, layout::bordered(basic::title("foo", None, None), &box_chars::LIGHT).title("bar"))?;
+///
+///     // ┌─bar─┐
+///     // │ foo │
+///     // └─────┘
+///     assert_eq!(canvas.get(&(0, 0))?.text, '┌');
+///     assert_eq!(canvas.get(&(2, 0))?.text, 'b');
+///     assert_eq!(canvas.get(&(2, 1))?.text, 'f');
+///
+///     // a wider title centers the inner widget
+///     let mut canvas = Basic::new(&(7, 3));
+///     let info = canvas.draw(&Just::Centered, layout::bordered(basic::title("a", None, None), &box_chars::LIGHT).title("title"))?;
+///     assert_eq!(info.shape.size, (7, 3));
+///
+///     // ┌title┐
+///     // │  a  │
+///     // └─────┘
+///     assert_eq!(canvas.get(&(3, 1))?.text, 'a');
+///
+///     // wide titles are measured by the columns they take up
+///     let mut canvas = Basic::new(&(8, 3));
+///     let info = canvas.draw(&Just::Centered, layout::bordered(basic::title("a", None, None), &box_chars::LIGHT).title("日本語"))?;
+///     assert_eq!(info.shape.size, (8, 3));
+///     Ok(())
+/// }
+/// ```
+#[must_use]
+pub fn bordered<W: WidgetSource>(inner: W, chars: &'static box_chars::Chars) -> Bordered<W::Output> {
+    Bordered { inner: inner.build(), chars, title: None }
+}
+
+impl<W: Widget> Bordered<W> {
+    #[must_use]
+    pub fn title(self, title: impl ToString) -> Self {
+        Self { title: Some(title.to_string()), ..self }
+    }
+}
+
+impl<W: Widget> Widget for Bordered<W> {
+    fn size(&self, canvas_size: &impl Size) -> Result<Vec2, Error> {
+        let mut size = self.inner.size(&(Vec2::from_size(canvas_size) - 2))? + 2;
+        if let Some(title) = &self.title {
+            // leave room for the corners
+            size.x = size.x.max(crate::canvas::display_width(title)? + 2);
+        }
+        Ok(size)
+    }
+
+    fn draw<C: Canvas>(self, canvas: &mut C) -> Result<(), Error> {
+        let size = Vec2::from_size(canvas);
+        canvas.rect_absolute(&Vec2::new(0, 0), &size, self.chars)?;
+        if let Some(title) = &self.title {
+            canvas.text(&Just::CenteredOnRow(0), title)?;
+        }
+        // the title can make the border wider than the inner widget, so keep it at its own size
+        canvas.window_absolute(&Vec2::new(1, 1), &(size - 2))?
+            .draw(&Just::Centered, self.inner)?;
+        Ok(())
+    }
+
+    fn name() -> &'static str { "bordered" }
+}
@@ -5,7 +5,7 @@
 //! Use [`basic`], [`themed`], or [`selectable`] for built-in widgets, or create new ones using
 //! [`widget!`]
 
-use crate::{prelude::*, num::Size};
+use crate::{prelude::*, num::Size, shapes::Rect};
 
 /// Constructs a [`Widget`] using the specified parameters
 ///
@@ -629,14 +629,17 @@ pub mod prelude {
 }
 
 /// Some common drawable object that's too complex to be included in [`Canvas`]
-pub trait Widget {
+///
+/// Widgets can also be boxed into a `Box<dyn Widget>` to store different types of widgets
+/// together, see [`DynWidget`]
+pub trait Widget: DynWidget {
     /// Gets the size of the widget to be drawn while potentially using the `canvas_size`
     ///
     /// # Errors
     ///
     /// - If there is some error into getting the size, such as when some text's length is too long
     /// to fit into an [`isize`]
-    fn size(&self, canvas_size: &impl Size) -> Result<Vec2, Error>;
+    fn size(&self, canvas_size: &impl Size) -> Result<Vec2, Error> where Self: Sized;
     /// Draws the widget onto the canvas
     ///
     /// The input `canvas` must be the same size as given by [`Self::size`]
@@ -644,9 +647,96 @@ pub trait Widget {
     /// # Errors
     ///
     /// - If the drawing of the widget has an error
-    fn draw<C: Canvas>(self, canvas: &mut C) -> Result<(), Error>;
+    fn draw<C: Canvas>(self, canvas: &mut C) -> Result<(), Error> where Self: Sized;
     /// The name of the widget to be used in error messages
-    fn name() -> &'static str;
+    fn name() -> &'static str where Self: Sized;
+    /// Gets the rectangle the widget would take up when drawn onto a canvas of size `canvas` with
+    /// `justification`
+    ///
+    /// # Errors
+    ///
+    /// - If the size of the widget has an error, see [`Self::size`]
+    /// - If the widget can't fit into the canvas with the justification
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let rect = widgets::basic::title("foo", None, None).bounding_box(&(7, 3), &Just::Centered)?;
+    ///
+    /// // .......
+    /// // . foo .
+    /// // .......
+    /// assert_eq!(rect.pos, (1, 1));
+    /// assert_eq!(rect.size, (5, 1));
+    /// # Ok(()) }
+    /// ```
+    fn bounding_box(&self, canvas: &impl Size, justification: &Just) -> Result<Rect, Error> where Self: Sized {
+        let size = self.size(canvas)?;
+        let pos = justification.get(canvas, &size)?;
+        Ok(Rect { pos, size })
+    }
+}
+
+/// The object-safe version of [`Widget`], which lets widgets be used as a `Box<dyn Widget>`
+///
+/// This is implemented automatically for every widget
+///
+/// # Example
+///
+/// ```
+/// # use canvas_tui::prelude::*;
+/// # fn main() -> Result<(), Error> {
+/// let widgets: Vec<Box<dyn Widget>> = vec![
+///     Box::new(widgets::basic::title("foo", None, None)),
+///     Box::new(widgets::basic::toggle("bar", true, None, None)),
+/// ];
+///
+/// let mut canvas = Basic::new(&(11, 4));
+/// for (widget, row) in widgets.into_iter().zip(1..) {
+///     canvas.draw(&Just::CenteredOnRow(row), widget)?;
+/// }
+///
+/// // ...........
+/// // ... foo ...
+/// // .  bar  ✓ .
+/// // ...........
+/// assert_eq!(canvas.get(&(4, 1))?.text, 'f');
+/// assert_eq!(canvas.get(&(8, 2))?.text, '✓');
+/// # Ok(()) }
+/// ```
+pub trait DynWidget {
+    /// Gets the size of the widget, see [`Widget::size`]
+    ///
+    /// # Errors
+    ///
+    /// - If there is some error into getting the size
+    fn size_dyn(&self, canvas_size: Vec2) -> Result<Vec2, Error>;
+    /// Draws the widget onto a [type-erased canvas](DynCanvas), see [`Widget::draw`]
+    ///
+    /// # Errors
+    ///
+    /// - If the drawing of the widget has an error
+    fn draw_dyn(self: Box<Self>, canvas: &mut DynCanvas<'_>) -> Result<(), Error>;
+    /// The name of the widget, see [`Widget::name`]
+    fn name_dyn(&self) -> &'static str;
+}
+
+impl<W: Widget> DynWidget for W {
+    fn size_dyn(&self, canvas_size: Vec2) -> Result<Vec2, Error> { self.size(&canvas_size) }
+    fn draw_dyn(self: Box<Self>, canvas: &mut DynCanvas<'_>) -> Result<(), Error> { (*self).draw(canvas) }
+    fn name_dyn(&self) -> &'static str { W::name() }
+}
+
+impl<'a> Widget for Box<dyn Widget + 'a> {
+    fn size(&self, canvas_size: &impl Size) -> Result<Vec2, Error> {
+        self.as_ref().size_dyn(Vec2::from_size(canvas_size))
+    }
+    fn draw<C: Canvas>(self, canvas: &mut C) -> Result<(), Error> {
+        self.draw_dyn(&mut DynCanvas::new(canvas))
+    }
+    fn name() -> &'static str { "boxed widget" }
 }
 
 /// A source of a [widget](Widget)
@@ -657,6 +747,40 @@ pub trait WidgetSource {
     type Output: Widget;
     /// Builds the source into a widget
     fn build(self) -> Self::Output;
+    /// Builds the source into a widget
+    ///
+    /// An alias of [`Self::build`]
+    fn into_widget(self) -> Self::Output where Self: Sized {
+        self.build()
+    }
+    /// Builds the source into a boxed widget, so it can be stored alongside other types of widgets
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use canvas_tui::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let widgets = vec![
+    ///     widgets::basic::title("foo", None, None).boxed(),
+    ///     widgets::basic::rolling_selection("bar", None, None, None).at_start(true).boxed(),
+    /// ];
+    ///
+    /// let mut canvas = Basic::new(&(11, 4));
+    /// for (widget, row) in widgets.into_iter().zip(1..) {
+    ///     canvas.draw(&Just::CenteredOnRow(row), widget)?;
+    /// }
+    ///
+    /// // ...........
+    /// // ... foo ...
+    /// // .   bar → .
+    /// // ...........
+    /// assert_eq!(canvas.get(&(4, 1))?.text, 'f');
+    /// assert_eq!(canvas.get(&(8, 2))?.text, '→');
+    /// # Ok(()) }
+    /// ```
+    fn boxed<'a>(self) -> Box<dyn Widget + 'a> where Self: Sized, Self::Output: 'a {
+        Box::new(self.build())
+    }
 }
 
 impl<W: Widget> WidgetSource for W {
@@ -694,6 +818,19 @@ fn width_or_length(width: Option<usize>, string: &str, padding: usize) -> Result
     len.try_into().map_err(|_| Error::TooLarge("text length", len))
 }
 
+/// Checks that the given `width` of the widget `name` is at least `min`
+fn check_min_width(width: Option<usize>, min: usize, name: &'static str) -> Result<(), Error> {
+    match width {
+        Some(width) if width < min => Err(Error::ItemTooBig {
+            pos: Vec2::new(0, 0),
+            size: Vec2::new(min.try_into().map_err(|_| Error::TooLarge("min width", min))?, 1),
+            canvas: Vec2::new(width.try_into().map_err(|_| Error::TooLarge("width", width))?, 1),
+            name,
+        }),
+        _ => Ok(()),
+    }
+}
+
 /// Gets the length of the string
 fn length_of(string: &str) -> Result<isize, Error> {
     let len = string.chars().count();
@@ -703,5 +840,6 @@ fn length_of(string: &str) -> Result<isize, Error> {
 pub mod basic;
 pub mod themed;
 pub mod selectable;
+pub mod layout;
 pub use themed::{Themed, Theme};
 pub use selectable::{Selectable, SelectableTheme};
@@ -78,6 +78,7 @@
 use crate::prelude::*;
 use widgets::prelude::*;
 use widgets::themed::Theme;
+use crate::shapes::Rect;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Selection {
@@ -136,6 +137,69 @@ impl<T: SelectableTheme> Selectable<usize, T> {
     pub const fn num(theme: T, val: usize, activated: bool) -> Self {
         Self { theme, selection: val, activated }
     }
+
+    /// A row of `steps` connected by arrows, centered in `width` cells, showing progress through
+    /// a multi-step flow
+    ///
+    /// Steps before `current_step` are colored as hovered, `current_step` as activated, and the
+    /// rest as normal [buttons](Self::button)
+    ///
+    /// # Errors
+    ///
+    /// - If `width` can't fit all of the steps and arrows
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ·······················
+    /// ·-foo- → -bar- → -baz-· (highlight represented by -)
+    /// ·······················
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    /// use widgets::{Theme, SelectableTheme};
+    /// # fn main() -> Result<(), Error> {
+    /// let widgets = widgets::Selectable::num(Frappe, 0, false);
+    ///
+    /// let mut canvas = Basic::new(&(23, 3));
+    /// canvas.draw(&Just::Centered, widgets.wizard(&["foo", "bar", "baz"], 1, 21))?;
+    ///
+    /// // ·······················
+    /// // ·-foo- → -bar- → -baz-· (highlight represented by -)
+    /// // ·······················
+    /// assert_eq!(canvas.get(&(2, 1))?.foreground, Some(Frappe.button_fg_hover()));
+    /// assert_eq!(canvas.get(&(7, 1))?.text, '→');
+    /// assert_eq!(canvas.get(&(10, 1))?.foreground, Some(Frappe.button_fg_activated()));
+    /// assert_eq!(canvas.get(&(18, 1))?.background, Some(Frappe.button_bg()));
+    /// Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn wizard(&self, steps: &[impl ToString], current_step: usize, width: usize) -> impl Widget {
+        let mut widgets = Vec::new();
+        for (step, text) in steps.iter().enumerate() {
+            if step > 0 {
+                widgets.push(super::basic::centered_label(" → ", None, None).boxed());
+            }
+            let selection = match step.cmp(&current_step) {
+                std::cmp::Ordering::Less => Selection::Selected,
+                std::cmp::Ordering::Equal => Selection::Activated,
+                std::cmp::Ordering::Greater => Selection::Deselected,
+            };
+            widgets.push(super::basic::highlighted_text(
+                text.to_string(),
+                self.theme.select_button_fg(selection),
+                self.theme.select_button_bg(selection),
+            ).boxed());
+        }
+        // a width too large to fit in an isize can't fit on the canvas either
+        let width = width.try_into().unwrap_or(isize::MAX);
+        layout::aligned(layout::hstack(widgets, 0), &(width, 1), Just::Centered)
+    }
 }
 
 impl<V: PartialEq, T: SelectableTheme> Selectable<V, T> {
@@ -143,6 +207,67 @@ impl<V: PartialEq, T: SelectableTheme> Selectable<V, T> {
         Self { theme, selection, activated }
     }
 
+    /// Replaces the selection, keeping the theme and whether it's activated
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    ///
+    /// let widgets = widgets::Selectable::num(Frappe, 0, true).with_selection(2);
+    /// assert!(widgets.activated(&2));
+    /// ```
+    #[must_use]
+    pub fn with_selection(self, selection: V) -> Self {
+        Self { selection, ..self }
+    }
+
+    /// Replaces whether the selection is activated, keeping the theme and selection
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    ///
+    /// let widgets = widgets::Selectable::num(Frappe, 2, false).with_activated(true);
+    /// assert!(widgets.activated(&2));
+    /// ```
+    #[must_use]
+    pub fn with_activated(self, activated: bool) -> Self {
+        Self { activated, ..self }
+    }
+
+    /// Finds the selection whose drawn rect contains `pos`, for mapping the mouse back onto the widgets
+    ///
+    /// `widgets` pairs each selection with where it was drawn, such as the shape given by [`into_rect`](DrawResultMethods::into_rect)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    /// # fn main() -> Result<(), Error> {
+    /// let widgets = widgets::Selectable::num(Frappe, 0, false);
+    ///
+    /// let mut canvas = Basic::new(&(20, 3));
+    /// let first = canvas.draw(&Just::CenterLeft, widgets.button(&0, "first"))?.shape;
+    /// let second = canvas.draw(&Just::CenterRight, widgets.button(&1, "second"))?.shape;
+    /// let drawn = [(0, first), (1, second)];
+    ///
+    /// assert_eq!(widgets.hovered_at(Vec2::new(2, 1), &drawn), Some(&0));
+    /// assert_eq!(widgets.hovered_at(Vec2::new(17, 1), &drawn), Some(&1));
+    /// assert_eq!(widgets.hovered_at(Vec2::new(9, 1), &drawn), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn hovered_at<'v>(&self, pos: Vec2, widgets: &'v [(V, Rect)]) -> Option<&'v V> {
+        widgets.iter()
+            .find(|(_, rect)| rect.contains(pos))
+            .map(|(selection, _)| selection)
+    }
+
     pub fn selected(&self, val: &V) -> Selection {
         match (self.selection.eq(val), self.activated) {
             (false, _) => Selection::Deselected,
@@ -209,6 +334,114 @@ widget! {
     )
 }
 
+widget! {
+    parent: Selectable<V: PartialEq, T: SelectableTheme>,
+    /// A selectable row of a menu with a `label` on the left and a `shortcut` on the right,
+    /// `width` cells wide
+    ///
+    /// # Errors
+    ///
+    /// - If `width` can't fit the label, the shortcut, and a space around and between them
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ·····················
+    /// ·-Open File  Ctrl+O-· (highlight represented by -)
+    /// ·····················
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    /// use widgets::{Theme, SelectableTheme};
+    /// # fn main() -> Result<(), Error> {
+    /// let widgets = widgets::Selectable::num(Frappe, 1, false);
+    ///
+    /// let mut canvas = Basic::new(&(16, 4));
+    /// canvas
+    ///     .draw(&Just::CenteredOnRow(1), widgets.menu_item(&0, "Open", "Ctrl+O", 14))
+    ///     .draw(&Just::CenteredOnRow(2), widgets.menu_item(&1, "Save", "Ctrl+S", 14))?;
+    ///
+    /// // ················
+    /// // ·-Open  Ctrl+O-· (highlight represented by -)
+    /// // ·-Save  Ctrl+S-· selected!
+    /// // ················
+    /// assert_eq!(canvas.get(&(7, 1))?.background, Some(Frappe.button_bg()));
+    /// assert_eq!(canvas.get(&(7, 2))?.background, Some(Frappe.button_bg_hover()));
+    /// Ok(())
+    /// # }
+    /// ```
+    name: menu_item,
+    origin: menu_item in super::basic,
+    create: |&self, selection: &V, label: &'a str, shortcut: &'a str, width: usize| (
+        label,
+        shortcut,
+        width,
+        self.button_fg(selection),
+        self.button_bg(selection),
+    )
+}
+
+impl<V: PartialEq, T: SelectableTheme> Selectable<V, T> {
+    /// A bordered list of [menu items](Self::menu_item), each given as `(selection, label, shortcut)`
+    ///
+    /// Each item is `width` cells wide, so the whole menu is `width + 2` wide including the border
+    ///
+    /// # Errors
+    ///
+    /// - If `width` can't fit one of the items, see [`Self::menu_item`]
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ··················
+    /// ·┌──────────────┐·
+    /// ·│-Open  Ctrl+O-│· (highlight represented by -)
+    /// ·│-Save  Ctrl+S-│·
+    /// ·└──────────────┘·
+    /// ··················
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    /// use widgets::{Theme, SelectableTheme};
+    /// # fn main() -> Result<(), Error> {
+    /// let widgets = widgets::Selectable::num(Frappe, 1, false);
+    ///
+    /// let mut canvas = Basic::new(&(18, 6));
+    /// canvas.draw(&Just::Centered, widgets.context_menu(&[
+    ///     (0, "Open", "Ctrl+O"),
+    ///     (1, "Save", "Ctrl+S"),
+    /// ], 14))?;
+    ///
+    /// // ··················
+    /// // ·┌──────────────┐·
+    /// // ·│-Open  Ctrl+O-│· (highlight represented by -)
+    /// // ·│-Save  Ctrl+S-│· selected!
+    /// // ·└──────────────┘·
+    /// // ··················
+    /// assert_eq!(canvas.get(&(1, 1))?.text, '┌');
+    /// assert_eq!(canvas.get(&(3, 2))?.text, 'O');
+    /// assert_eq!(canvas.get(&(3, 2))?.background, Some(Frappe.button_bg()));
+    /// assert_eq!(canvas.get(&(3, 3))?.background, Some(Frappe.button_bg_hover()));
+    /// Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn context_menu<'a>(&'a self, items: &[(V, &'a str, &'a str)], width: usize) -> impl Widget + 'a {
+        let rows = items.iter()
+            .map(|(selection, label, shortcut)| self.menu_item(selection, label, shortcut, width).boxed())
+            .collect();
+        layout::bordered(layout::vstack(rows, 0), &box_chars::LIGHT)
+    }
+}
+
 widget! {
     parent: Selectable<V: PartialEq, T: SelectableTheme>,
     /// A toggleable button
@@ -238,10 +471,12 @@ widget! {
     /// # Optionals
     ///
     /// - [`max_width: usize`](TitledText::max_width)
+    /// - [`min_width: usize`](TitledText::min_width)
     ///
     /// # Style
     ///
-    /// The width adjusts to the widest line of text or `max_width` if it is hit
+    /// The width adjusts to the widest line of text or `max_width` if it is hit, and is padded
+    /// up to `min_width`
     ///
     /// ```text
     /// ···············
@@ -291,6 +526,21 @@ widget! {
     ///     Ok(())
     /// }
     /// ```
+    ///
+    /// With a minimum width
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let widgets = widgets::Selectable::num(Frappe, 1, false);
+    ///     let titled_text = widgets.titled_text(1.., "Os", &["a", "b"]).min_width(8);
+    ///
+    ///     assert_eq!(titled_text.size(&(20, 20))?, Vec2::new(8, 3));
+    ///     Ok(())
+    /// }
+    /// ```
     name: titled_text,
     args: (
         selections: Vec<V> [impl IntoIterator<Item = V> > .into_iter().take(text.len()).collect()],
@@ -299,9 +549,13 @@ widget! {
     ),
     optionals: (
         max_width: Option<usize>,
+        min_width: Option<usize>,
     ),
     size: |&self, _| {
-        basic::titled_text_bounds(&self.title, &self.text, self.max_width)
+        let size = basic::titled_text_bounds(&self.title, &self.text, self.max_width)?;
+        let min_width: isize = self.min_width.unwrap_or_default().try_into()
+            .map_err(|_| Error::TooLarge("min width", self.min_width.unwrap_or_default()))?;
+        Ok(size.with_x(size.x.max(min_width)))
     },
     draw: |self, canvas| {
         let theme = &self.parent.theme;
@@ -349,10 +603,13 @@ widget! {
     /// # Optionals
     ///
     /// - [`highlighted: Color`](RollingSelection::highlighted) (default: None)
+    /// - [`step_by: usize`](RollingSelection::step_by) (default: 1), see [`RollingSelection::step`]
     /// - [after `build`](WidgetSource::build),
     ///     - [`at_start: bool`](super::basic::RollingSelection::at_start) (default: false)
     ///     - [`at_end: bool`](super::basic::RollingSelection::at_start) (default: false)
     ///     - [`truncate_from_end: bool`](super::basic::RollingSelection::truncate_from_end)
+    ///     - [`arrow_chars: (char, char)`](super::basic::RollingSelection::arrow_chars) (default: ('←', '→'))
+    ///     - [`center_label: bool`](super::basic::RollingSelection::center_label) (default: true)
     ///
     /// *Note:
     /// [`RollingSelection::truncate_from_end`](super::basic::RollingSelection::truncate_from_end)
@@ -374,6 +631,7 @@ widget! {
     ),
     optionals: (
         highlighted: Option<Color>,
+        step_by: Option<usize>,
     ),
     build: |self| (
         self.text,
@@ -386,3 +644,25 @@ widget! {
         self.highlighted.unwrap_or_else(|| self.parent.rolling_selection_bg(&self.selection))
     ).truncate_from_end(self.parent.activated(&self.selection))
 }
+
+impl<'a, V: PartialEq, T: SelectableTheme> RollingSelection<'a, V, T> {
+    /// How far each arrow moves the selection, set with [`step_by`](Self::step_by)
+    ///
+    /// This doesn't change how the widget is drawn, it's kept alongside the widget so the code
+    /// handling input can read it back. Drawing consumes the widget, so read it before then
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    ///
+    /// let widgets = widgets::Selectable::num(Frappe, 0, false);
+    /// let year = widgets.rolling_selection(0, "2023", None).step_by(10);
+    /// assert_eq!(year.step(), 10);
+    /// ```
+    #[must_use]
+    pub fn step(&self) -> usize {
+        self.step_by.unwrap_or(1)
+    }
+}
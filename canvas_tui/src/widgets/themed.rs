@@ -49,6 +49,7 @@
 
 use crate::prelude::*;
 use widgets::prelude::*;
+use crate::canvas::display_width;
 
 pub trait Theme {
     fn text(&self) -> Color;
@@ -68,6 +69,13 @@ pub trait Theme {
 
     fn rolling_selection_fg(&self) -> Color;
     fn rolling_selection_bg(&self) -> Color;
+
+    fn input_field_fg(&self) -> Color { self.titled_text_text_fg() }
+    fn input_field_bg(&self) -> Color { self.titled_text_text_bg() }
+    /// The color of the character under the cursor, defaults to the colors of the rest of the field
+    /// inverted
+    fn input_field_cursor_fg(&self) -> Color { self.input_field_bg() }
+    fn input_field_cursor_bg(&self) -> Color { self.input_field_fg() }
 }
 
 pub struct Themed<T: Theme> {
@@ -197,6 +205,8 @@ widget! {
     ///     - [`at_start: bool`](super::basic::RollingSelection::at_start) (default: false)
     ///     - [`at_end: bool`](super::basic::RollingSelection::at_end) (default: false)
     ///     - [`truncate_from_end: bool`](super::basic::RollingSelection::truncate_from_end) (default: false)
+    ///     - [`arrow_chars: (char, char)`](super::basic::RollingSelection::arrow_chars) (default: ('←', '→'))
+    ///     - [`center_label: bool`](super::basic::RollingSelection::center_label) (default: true)
     ///
     /// # Style
     ///
@@ -225,3 +235,127 @@ widget! {
         self.highlighted.unwrap_or_else(|| self.parent.theme.rolling_selection_bg()),
     )
 }
+
+widget! {
+    parent: Themed<T: Theme>,
+    /// A box showing the text being typed and the cursor at `cursor_pos`, `width` cells wide
+    ///
+    /// If the text doesn't fit, it scrolls so the cursor is always shown
+    ///
+    /// # Errors
+    ///
+    /// - If `width` is less than 3
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ···········
+    /// ·-foo=----· (highlight represented by -, cursor represented by =)
+    /// ···········
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    /// use widgets::Theme;
+    /// # fn main() -> Result<(), Error> {
+    /// let widgets = Frappe.into_themed();
+    ///
+    /// let mut canvas = Basic::new(&(8, 3));
+    /// canvas.draw(&Just::Centered, widgets.input_field("hello", 5, 6))?;
+    ///
+    /// // ········
+    /// // ·-llo=-· (highlight represented by -, cursor represented by =)
+    /// // ········
+    /// assert_eq!(canvas.get(&(2, 1))?.text, 'l');
+    /// assert_eq!(canvas.get(&(5, 1))?.background, Some(Frappe.input_field_cursor_bg()));
+    /// assert_eq!(canvas.get(&(4, 1))?.background, Some(Frappe.input_field_bg()));
+    /// Ok(())
+    /// # }
+    /// ```
+    name: input_field,
+    origin: input_field in super::basic,
+    create: |&self, text: &'a str, cursor_pos: usize, width: usize| (
+        text,
+        cursor_pos,
+        width,
+        self.theme.input_field_fg(),
+        self.theme.input_field_bg(),
+        self.theme.input_field_cursor_fg(),
+        self.theme.input_field_cursor_bg(),
+    )
+}
+
+impl<T: Theme> Themed<T> {
+    /// A search box `width` cells wide, with a 🔍 before the `query` and the amount of results
+    /// after it if `result_count` is given
+    ///
+    /// The cursor is placed at the end of the query, see [`Self::input_field`]
+    ///
+    /// # Errors
+    ///
+    /// - If `width` doesn't leave at least 3 cells for the input field
+    ///
+    /// # Style
+    ///
+    /// ```text
+    /// ·······················
+    /// ·🔍-foo=- (2 results) · (highlight represented by -, cursor represented by =)
+    /// ·······················
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use canvas_tui::prelude::*;
+    /// use themes::catppuccin::Frappe;
+    /// use widgets::Theme;
+    /// # fn main() -> Result<(), Error> {
+    /// let widgets = Frappe.into_themed();
+    ///
+    /// let mut canvas = Basic::new(&(23, 3));
+    /// canvas.draw(&Just::Centered, widgets.search_bar("foo", Some(2), 21))?;
+    ///
+    /// // ·······················
+    /// // ·🔍-foo=- (2 results) · (highlight represented by -, cursor represented by =)
+    /// // ·······················
+    /// assert_eq!(canvas.get(&(1, 1))?.text, '🔍');
+    /// assert_eq!(canvas.get(&(4, 1))?.text, 'f');
+    /// assert_eq!(canvas.get(&(7, 1))?.background, Some(Frappe.input_field_cursor_bg()));
+    /// assert_eq!(canvas.get(&(10, 1))?.text, '(');
+    ///
+    /// // a single result isn't plural
+    /// let mut canvas = Basic::new(&(23, 3));
+    /// canvas.draw(&Just::Centered, widgets.search_bar("foo", Some(1), 21))?;
+    /// assert_eq!(canvas.get(&(11, 1))?.text, '(');
+    /// assert_eq!(canvas.get(&(20, 1))?.text, ')');
+    /// Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn search_bar<'a>(&'a self, query: &'a str, result_count: Option<usize>, width: usize) -> impl Widget + 'a {
+        const ICON: char = '🔍';
+        let foreground = self.theme.input_field_fg();
+        let background = self.theme.input_field_bg();
+
+        let width_of = |text: &str| display_width(text).map_or(0, isize::unsigned_abs);
+        let suffix = result_count.map(|count| match count {
+            1 => String::from("(1 result)"),
+            count => format!("({count} results)"),
+        });
+        // the suffix has a space on each side
+        let suffix_width = suffix.as_deref().map_or(0, |suffix| width_of(suffix) + 2);
+        let field_width = width.saturating_sub(width_of(&ICON.to_string()) + suffix_width);
+
+        let mut widgets = vec![
+            super::basic::icon(ICON, foreground, background).boxed(),
+            self.input_field(query, query.chars().count(), field_width).boxed(),
+        ];
+        if let Some(suffix) = suffix {
+            widgets.push(super::basic::highlighted_text(suffix, foreground, background).boxed());
+        }
+        layout::hstack(widgets, 0)
+    }
+}